@@ -0,0 +1,107 @@
+use crate::contract;
+use crate::error::RegistryError;
+use crate::storage;
+use crate::types::{Attestation, Role};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+// Third-party attestations
+//
+// Holders of the Attester role vouch for an expert's claims by hash. An
+// attestation counts towards the verification policy while it is not revoked,
+// not expired and its attester still holds the role.
+
+/// Maximum number of attestations kept per expert, revoked ones included
+pub const MAX_ATTESTATIONS: u32 = 20;
+
+/// Record or replace the attester's attestation for `claim_type`
+pub fn attest(
+    env: &Env,
+    attester: &Address,
+    expert: &Address,
+    claim_type: Symbol,
+    claim_hash: BytesN<32>,
+    expires_at: u64,
+) -> Result<(), RegistryError> {
+    if expires_at != 0 && expires_at <= env.ledger().timestamp() {
+        return Err(RegistryError::InvalidExpiry);
+    }
+
+    let attestation = Attestation {
+        attester: attester.clone(),
+        claim_type: claim_type.clone(),
+        claim_hash,
+        issued_at: env.ledger().timestamp(),
+        expires_at,
+        revoked: false,
+    };
+
+    let mut attestations = storage::get_attestations(env, expert);
+    match position(&attestations, attester, &claim_type) {
+        Some(i) => attestations.set(i, attestation),
+        None => {
+            if attestations.len() >= MAX_ATTESTATIONS {
+                return Err(RegistryError::TooManyAttestations);
+            }
+            attestations.push_back(attestation);
+        }
+    }
+    storage::set_attestations(env, expert, &attestations);
+
+    Ok(())
+}
+
+/// Mark the attester's attestation for `claim_type` as revoked
+pub fn revoke(
+    env: &Env,
+    attester: &Address,
+    expert: &Address,
+    claim_type: &Symbol,
+) -> Result<(), RegistryError> {
+    let mut attestations = storage::get_attestations(env, expert);
+    let i =
+        position(&attestations, attester, claim_type).ok_or(RegistryError::AttestationNotFound)?;
+
+    let mut attestation = attestations.get(i).unwrap();
+    if attestation.revoked {
+        return Err(RegistryError::AttestationNotFound);
+    }
+    attestation.revoked = true;
+    attestations.set(i, attestation);
+    storage::set_attestations(env, expert, &attestations);
+
+    Ok(())
+}
+
+/// Fail unless the expert meets the attestation policy, if one is set
+pub fn ensure_attested(env: &Env, expert: &Address) -> Result<(), RegistryError> {
+    let Some(policy) = storage::get_attestation_policy(env) else {
+        return Ok(());
+    };
+
+    let now = env.ledger().timestamp();
+    let live = storage::get_attestations(env, expert)
+        .iter()
+        .filter(|a| {
+            !a.revoked
+                && (a.expires_at == 0 || a.expires_at > now)
+                && policy.claim_types.contains(&a.claim_type)
+                && contract::has_role(env, Role::Attester, &a.attester)
+        })
+        .count() as u32;
+
+    if live < policy.required {
+        return Err(RegistryError::NotEnoughAttestations);
+    }
+    Ok(())
+}
+
+fn position(
+    attestations: &Vec<Attestation>,
+    attester: &Address,
+    claim_type: &Symbol,
+) -> Option<u32> {
+    attestations
+        .iter()
+        .position(|a| &a.attester == attester && &a.claim_type == claim_type)
+        .map(|i| i as u32)
+}
@@ -0,0 +1,126 @@
+use crate::error::RegistryError;
+use crate::events;
+use crate::storage;
+use crate::types::{BondConfig, ExpertStatus};
+use soroban_sdk::{token, Address, Env};
+
+// Verification bond
+//
+// When the configured amount is above zero, an expert must have at least that
+// much deposited before they can be verified. Bans slash `slash_bps` of the
+// bond to the treasury; the rest can be withdrawn once the cooldown has passed.
+
+/// Upper bound for `slash_bps` (100%)
+pub const MAX_SLASH_BPS: u32 = 10_000;
+
+/// Validate and store a new configuration
+pub fn configure(env: &Env, config: &BondConfig) -> Result<(), RegistryError> {
+    if config.amount < 0 || config.slash_bps > MAX_SLASH_BPS {
+        return Err(RegistryError::InvalidBondConfig);
+    }
+
+    // Bonds already held are denominated in the current token
+    if let Some(current) = storage::get_bond_config(env) {
+        if current.token != config.token && storage::get_bonded_total(env) > 0 {
+            return Err(RegistryError::BondsOutstanding);
+        }
+    }
+
+    storage::set_bond_config(env, config);
+    Ok(())
+}
+
+/// Fail unless the expert's bond covers the configured amount
+pub fn ensure_bonded(env: &Env, expert: &Address) -> Result<(), RegistryError> {
+    if let Some(config) = storage::get_bond_config(env) {
+        if storage::get_expert_record(env, expert).bond < config.amount {
+            return Err(RegistryError::BondRequired);
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if verification currently requires a bond
+pub fn is_required(env: &Env) -> bool {
+    storage::get_bond_config(env).is_some_and(|config| config.amount > 0)
+}
+
+/// Move `amount` from the expert into the registry. The caller must have checked auth.
+pub fn deposit(env: &Env, expert: &Address, amount: i128) -> Result<(), RegistryError> {
+    if amount <= 0 {
+        return Err(RegistryError::InvalidBondAmount);
+    }
+    let config = storage::get_bond_config(env).ok_or(RegistryError::BondNotConfigured)?;
+
+    token::Client::new(env, &config.token).transfer(
+        expert,
+        env.current_contract_address(),
+        &amount,
+    );
+
+    let mut record = storage::get_expert_record(env, expert);
+    record.bond += amount;
+    record.bonded_at = env.ledger().timestamp();
+    storage::save_expert_record(env, expert, &record);
+    storage::set_bonded_total(env, storage::get_bonded_total(env) + amount);
+
+    events::emit_bond_deposited(env, expert.clone(), amount, record.bond);
+    Ok(())
+}
+
+/// Send `slash_bps` of the expert's bond to the treasury
+pub fn slash(env: &Env, expert: &Address) {
+    let Some(config) = storage::get_bond_config(env) else {
+        return;
+    };
+
+    let mut record = storage::get_expert_record(env, expert);
+    let slashed = record.bond * (config.slash_bps as i128) / (MAX_SLASH_BPS as i128);
+    if slashed == 0 {
+        return;
+    }
+
+    token::Client::new(env, &config.token).transfer(
+        &env.current_contract_address(),
+        &config.treasury,
+        &slashed,
+    );
+
+    record.bond -= slashed;
+    storage::save_expert_record(env, expert, &record);
+    storage::set_bonded_total(env, storage::get_bonded_total(env) - slashed);
+
+    events::emit_bond_slashed(env, expert.clone(), slashed, record.bond);
+}
+
+/// Return the expert's whole bond once the cooldown has passed.
+/// The caller must have checked auth. Returns the amount withdrawn.
+pub fn withdraw(env: &Env, expert: &Address) -> Result<i128, RegistryError> {
+    let mut record = storage::get_expert_record(env, expert);
+    if record.bond == 0 {
+        return Err(RegistryError::NoBond);
+    }
+    if storage::effective_status(env, &record) == ExpertStatus::Suspended {
+        return Err(RegistryError::ExpertSuspended);
+    }
+
+    // A bond can only exist once a configuration has been set
+    let config = storage::get_bond_config(env).ok_or(RegistryError::BondNotConfigured)?;
+    if env.ledger().timestamp() < record.bonded_at.saturating_add(config.cooldown) {
+        return Err(RegistryError::BondCooldown);
+    }
+
+    let amount = record.bond;
+    token::Client::new(env, &config.token).transfer(
+        &env.current_contract_address(),
+        expert,
+        &amount,
+    );
+
+    record.bond = 0;
+    storage::save_expert_record(env, expert, &record);
+    storage::set_bonded_total(env, storage::get_bonded_total(env) - amount);
+
+    events::emit_bond_withdrawn(env, expert.clone(), amount, 0);
+    Ok(amount)
+}
@@ -1,7 +1,36 @@
+use crate::attestations;
+use crate::bond;
+use crate::council;
 use crate::events;
+use crate::handles;
+use crate::history;
+use crate::identity::{self, RECOVERY_TIMELOCK};
+use crate::migration::{self, CONTRACT_VERSION};
+use crate::sbt;
+use crate::skills;
 use crate::storage;
-use crate::{error::RegistryError, types::ExpertStatus};
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::uri;
+use crate::{
+    error::{IdentityError, RegistryError},
+    types::{
+        Appeal, AppealStatus, Application, ApplicationPage, ApplicationStatus, Attestation,
+        AttestationPolicy, BatchResult, BondConfig, Council, CouncilAction, ExpertPage,
+        ExpertStatus, PauseGroup, Proposal, RecoveryRequest, Role, StatusHistoryPage, UriPolicy,
+    },
+};
+use core::ops::Range;
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+/// Maximum number of entries scanned by a single page of any listing
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Indexes covered by a page of at most `limit` (clamped to 1..=MAX_PAGE_SIZE)
+/// entries out of `total`, and where the next page starts (None after the last)
+fn page(total: u64, start: u64, limit: u32) -> (Range<u64>, Option<u64>) {
+    let end = total.min(start.saturating_add(limit.clamp(1, MAX_PAGE_SIZE) as u64));
+    let next_cursor = if end < total { Some(end) } else { None };
+    (start..end, next_cursor)
+}
 
 /// Initialize the registry with an admin address
 pub fn initialize_registry(env: &Env, admin: &Address) -> Result<(), RegistryError> {
@@ -10,121 +39,1019 @@ pub fn initialize_registry(env: &Env, admin: &Address) -> Result<(), RegistryErr
     }
 
     storage::set_admin(env, admin);
+    storage::set_version(env, CONTRACT_VERSION);
 
     Ok(())
 }
 
-/// Verify an expert by setting their status to Verified (Admin only)
+/// Replace the contract code in place (Admin only)
+/// Call `migrate` afterwards if the new code changes the storage layout
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    admin.require_auth();
+    council::ensure_inactive(env)?;
+
+    apply_upgrade(env, new_wasm_hash, &admin);
+    Ok(())
+}
+
+pub(crate) fn apply_upgrade(env: &Env, new_wasm_hash: BytesN<32>, by: &Address) {
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+    events::emit_upgraded(env, new_wasm_hash, by.clone());
+}
+
+/// Rewrite records stored by an older version into the current layout (Admin only)
+pub fn migrate(env: &Env, start: u64, limit: u32) -> Result<Option<u64>, RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    admin.require_auth();
+
+    let from_version = storage::get_version(env);
+    if from_version >= CONTRACT_VERSION {
+        return Err(RegistryError::AlreadyMigrated);
+    }
+
+    let (next_cursor, records) = migration::migrate_from_v1(env, start, limit);
+    if next_cursor.is_some() {
+        return Ok(next_cursor);
+    }
+
+    // The version only moves once every page has been rewritten
+    storage::set_version(env, CONTRACT_VERSION);
+    events::emit_migrated(env, from_version, CONTRACT_VERSION, records);
+
+    Ok(None)
+}
+
+/// Get the storage layout version
+pub fn get_version(env: &Env) -> u32 {
+    storage::get_version(env)
+}
+
+/// Propose a new admin (Admin only); takes effect once accepted
+pub fn transfer_admin(env: &Env, new_admin: &Address) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    admin.require_auth();
+    council::ensure_inactive(env)?;
+
+    apply_transfer_admin(env, new_admin)
+}
+
+pub(crate) fn apply_transfer_admin(env: &Env, new_admin: &Address) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+
+    storage::set_pending_admin(env, new_admin);
+    events::emit_admin_transfer_proposed(env, admin, new_admin.clone());
+
+    Ok(())
+}
+
+/// Accept a pending admin transfer (Proposed admin only)
+/// Installing a council drops any transfer proposed before it, so while a
+/// council is active the pending admin can only have come from a proposal.
+pub fn accept_admin(env: &Env) -> Result<(), RegistryError> {
+    let old_admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    let new_admin = storage::get_pending_admin(env).ok_or(RegistryError::NoPendingAdmin)?;
+    new_admin.require_auth();
+
+    storage::set_admin(env, &new_admin);
+    storage::remove_pending_admin(env);
+    events::emit_admin_transferred(env, old_admin, new_admin);
+
+    Ok(())
+}
+
+/// Check if an account holds a role (the admin implicitly holds every role)
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    storage::get_admin(env).as_ref() == Some(account) || storage::has_role(env, role, account)
+}
+
+/// Require the caller's authorization and that they hold the given role
+fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), RegistryError> {
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    caller.require_auth();
+
+    if !has_role(env, role, caller) {
+        return Err(RegistryError::MissingRole);
+    }
+
+    Ok(())
+}
+
+/// Grant a role to an account (SuperAdmin only)
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+    council::ensure_inactive(env)?;
+
+    apply_grant_role(env, caller, role, account);
+    Ok(())
+}
+
+pub(crate) fn apply_grant_role(env: &Env, caller: &Address, role: Role, account: &Address) {
+    storage::grant_role(env, role, account);
+    events::emit_role_granted(env, role, account.clone(), caller.clone());
+}
+
+/// Revoke a role from an account (SuperAdmin only)
+pub fn revoke_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+    council::ensure_inactive(env)?;
+
+    apply_revoke_role(env, caller, role, account);
+    Ok(())
+}
+
+pub(crate) fn apply_revoke_role(env: &Env, caller: &Address, role: Role, account: &Address) {
+    storage::revoke_role(env, role, account);
+    events::emit_role_revoked(env, role, account.clone(), caller.clone());
+}
+
+/// Verify an expert by setting their status to Verified (Verifier only)
 /// Batch Verification
-pub fn batch_add_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
+pub fn batch_add_experts(
+    env: Env,
+    caller: Address,
+    experts: Vec<Address>,
+) -> Result<(), RegistryError> {
     if experts.len() > 20 {
         return Err(RegistryError::ExpertVecMax);
     }
 
-    let admin = storage::get_admin(&env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+    ensure_not_paused(&env, PauseGroup::Verification)?;
+    require_role(&env, &caller, Role::Verifier)?;
 
     for expert in experts {
-        let status = storage::get_expert_status(&env, &expert);
-        if status == ExpertStatus::Verified {
-            return Err(RegistryError::AlreadyVerified);
-        }
         // Default empty URI for batch adds
         let empty_uri = String::from_str(&env, "");
-        storage::set_expert_record(&env, &expert, ExpertStatus::Verified, empty_uri);
-        storage::add_expert_to_index(&env, &expert);
-        events::emit_status_change(&env, expert, status, ExpertStatus::Verified, admin.clone());
+        apply_verify(&env, &caller, &expert, empty_uri, empty_hash(&env))?;
     }
 
     Ok(())
 }
 
-/// Batch ban experts by setting their status to Banned (Admin only)
-pub fn batch_ban_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
+/// Verify each expert with their own profile URI (Verifier only).
+/// Items that fail are skipped and reported instead of reverting the batch.
+pub fn batch_add_experts_with_uris(
+    env: &Env,
+    caller: &Address,
+    experts: Vec<(Address, String)>,
+) -> Result<Vec<BatchResult>, RegistryError> {
     if experts.len() > 20 {
         return Err(RegistryError::ExpertVecMax);
     }
 
-    let admin = storage::get_admin(&env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+    ensure_not_paused(env, PauseGroup::Verification)?;
+    require_role(env, caller, Role::Verifier)?;
+
+    let mut results = Vec::new(env);
+    for (expert, data_uri) in experts {
+        let outcome = apply_verify(env, caller, &expert, data_uri, empty_hash(env));
+        results.push_back(batch_result(expert, outcome));
+    }
+
+    Ok(results)
+}
+
+/// Batch ban experts by setting their status to Banned (Moderator only)
+pub fn batch_ban_experts(
+    env: Env,
+    caller: Address,
+    experts: Vec<Address>,
+) -> Result<(), RegistryError> {
+    if experts.len() > 20 {
+        return Err(RegistryError::ExpertVecMax);
+    }
+
+    require_role(&env, &caller, Role::Moderator)?;
+    council::ensure_inactive(&env)?;
 
     for expert in experts {
-        let status = storage::get_expert_status(&env, &expert);
-        if status == ExpertStatus::Banned {
-            return Err(RegistryError::AlreadyBanned);
-        }
-        let existing = storage::get_expert_record(&env, &expert);
-        storage::set_expert_record(&env, &expert, ExpertStatus::Banned, existing.data_uri);
-        events::emit_status_change(&env, expert, status, ExpertStatus::Banned, admin.clone());
+        apply_ban(&env, &caller, &expert)?;
     }
 
     Ok(())
 }
 
-pub fn verify_expert(env: &Env, expert: &Address, data_uri: String) -> Result<(), RegistryError> {
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+/// Ban each expert (Moderator only).
+/// Items that fail are skipped and reported instead of reverting the batch.
+pub fn batch_ban_experts_with_results(
+    env: &Env,
+    caller: &Address,
+    experts: Vec<Address>,
+) -> Result<Vec<BatchResult>, RegistryError> {
+    if experts.len() > 20 {
+        return Err(RegistryError::ExpertVecMax);
+    }
 
-    admin.require_auth();
+    require_role(env, caller, Role::Moderator)?;
+    council::ensure_inactive(env)?;
+
+    let mut results = Vec::new(env);
+    for expert in experts {
+        let outcome = apply_ban(env, caller, &expert);
+        results.push_back(batch_result(expert, outcome));
+    }
+
+    Ok(results)
+}
+
+/// Verify an expert by setting their status to Verified (Verifier only)
+pub fn verify_expert(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    data_uri: String,
+    data_hash: BytesN<32>,
+) -> Result<(), RegistryError> {
+    ensure_not_paused(env, PauseGroup::Verification)?;
+    require_role(env, caller, Role::Verifier)?;
+
+    apply_verify(env, caller, expert, data_uri, data_hash)
+}
 
+// Every check runs before the first write, so a failed item leaves no trace
+fn apply_verify(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    data_uri: String,
+    data_hash: BytesN<32>,
+) -> Result<(), RegistryError> {
     let current_status = storage::get_expert_status(env, expert);
 
-    if current_status == ExpertStatus::Verified {
+    if matches!(
+        current_status,
+        ExpertStatus::Verified | ExpertStatus::Suspended
+    ) {
         return Err(RegistryError::AlreadyVerified);
     }
-
-    // Validate URI length (limit ~64 chars)
-    if data_uri.len() > 64 {
-        return Err(RegistryError::UriTooLong);
+    // Banned experts are only reinstated through an accepted appeal
+    if current_status == ExpertStatus::Banned {
+        return Err(RegistryError::AlreadyBanned);
+    }
+    if current_status == ExpertStatus::Migrated {
+        return Err(RegistryError::AlreadyMigrated);
     }
 
-    storage::set_expert_record(env, expert, ExpertStatus::Verified, data_uri);
+    uri::validate(env, &data_uri)?;
+
+    bond::ensure_bonded(env, expert)?;
+    attestations::ensure_attested(env, expert)?;
+
+    storage::set_expert_record(env, expert, ExpertStatus::Verified, data_uri, data_hash);
+    start_verification_period(env, expert);
+    // Lapsed experts keep their directory slot, so this only indexes first-time verifications
     storage::add_expert_to_index(env, expert);
+    // List the expert under any tags they kept
+    skills::index_expert(env, expert, &storage::get_expert_record(env, expert).skills);
+    sbt::mint(env, expert);
+    close_application(env, expert, ApplicationStatus::Approved, 0);
 
-    events::emit_status_change(
+    record_status_change(
         env,
         expert.clone(),
         current_status,
         ExpertStatus::Verified,
-        admin,
+        caller.clone(),
+        0,
     );
 
     Ok(())
 }
 
-/// Ban an expert by setting their status to Banned (Admin only)
-pub fn ban_expert(env: &Env, expert: &Address) -> Result<(), RegistryError> {
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+/// Ban an expert by setting their status to Banned (Moderator only)
+pub fn ban_expert(env: &Env, caller: &Address, expert: &Address) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Moderator)?;
+    council::ensure_inactive(env)?;
+
+    apply_ban(env, caller, expert)
+}
 
+pub(crate) fn apply_ban(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+) -> Result<(), RegistryError> {
     let current_status = storage::get_expert_status(env, expert);
 
     if current_status == ExpertStatus::Banned {
         return Err(RegistryError::AlreadyBanned);
     }
+    if current_status == ExpertStatus::Migrated {
+        return Err(RegistryError::AlreadyMigrated);
+    }
 
-    // Preserve existing data_uri when banning
+    // Preserve the rest of the record, suspension included, in case an appeal reinstates it
     let existing = storage::get_expert_record(env, expert);
-    storage::set_expert_record(env, expert, ExpertStatus::Banned, existing.data_uri);
+    let mut banned = existing.clone();
+    banned.status = ExpertStatus::Banned;
+    banned.updated_at = env.ledger().timestamp();
+    storage::save_expert_record(env, expert, &banned);
+    skills::unindex_expert(env, expert, &existing.skills);
+    sbt::burn(env, expert);
+    bond::slash(env, expert);
+    handles::quarantine(env, expert);
 
-    events::emit_status_change(
+    record_status_change(
         env,
         expert.clone(),
         current_status,
         ExpertStatus::Banned,
-        admin,
+        caller.clone(),
+        0,
+    );
+
+    Ok(())
+}
+
+/// Suspend a verified expert for `duration` seconds (Moderator only).
+/// Suspending an already suspended expert replaces the end time.
+pub fn suspend_expert(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    duration: u64,
+    reason_code: u32,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Moderator)?;
+    council::ensure_inactive(env)?;
+
+    apply_suspend(env, caller, expert, duration, reason_code)
+}
+
+pub(crate) fn apply_suspend(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    duration: u64,
+    reason_code: u32,
+) -> Result<(), RegistryError> {
+    if duration == 0 {
+        return Err(RegistryError::InvalidDuration);
+    }
+
+    let mut record = storage::get_expert_record(env, expert);
+    let current_status = storage::effective_status(env, &record);
+    if !matches!(
+        current_status,
+        ExpertStatus::Verified | ExpertStatus::Suspended
+    ) {
+        return Err(RegistryError::NotVerified);
+    }
+
+    let now = env.ledger().timestamp();
+    record.suspended_until = now.saturating_add(duration);
+    record.updated_at = now;
+    storage::save_expert_record(env, expert, &record);
+
+    record_status_change(
+        env,
+        expert.clone(),
+        current_status,
+        ExpertStatus::Suspended,
+        caller.clone(),
+        reason_code,
+    );
+
+    Ok(())
+}
+
+/// File an appeal against the expert's current ban (the banned expert only, once per ban)
+pub fn file_appeal(env: &Env, expert: &Address, evidence_uri: String) -> Result<(), RegistryError> {
+    expert.require_auth();
+
+    uri::validate(env, &evidence_uri)?;
+
+    let record = storage::get_expert_record(env, expert);
+    if record.status != ExpertStatus::Banned {
+        return Err(RegistryError::NotBanned);
+    }
+
+    // The ban's timestamp identifies it: a banned record is not rewritten until reinstated
+    if let Some(previous) = storage::get_appeal(env, expert) {
+        if previous.banned_at == record.updated_at {
+            return Err(RegistryError::AppealAlreadyFiled);
+        }
+    }
+
+    let appeal = Appeal {
+        evidence_uri: evidence_uri.clone(),
+        status: AppealStatus::Pending,
+        banned_at: record.updated_at,
+        filed_at: env.ledger().timestamp(),
+        resolved_at: None,
+        resolved_by: None,
+    };
+    storage::set_appeal(env, expert, &appeal);
+
+    events::emit_appeal_filed(env, expert.clone(), evidence_uri);
+    Ok(())
+}
+
+/// Accept an appeal, reinstating the expert to Verified, or reject it (Moderator only).
+/// Only an appeal against the expert's current ban can be accepted.
+pub fn resolve_appeal(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    accept: bool,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Moderator)?;
+
+    let mut appeal = storage::get_appeal(env, expert).ok_or(RegistryError::AppealNotFound)?;
+    if appeal.status != AppealStatus::Pending {
+        return Err(RegistryError::AppealAlreadyResolved);
+    }
+
+    appeal.resolved_at = Some(env.ledger().timestamp());
+    appeal.resolved_by = Some(caller.clone());
+
+    if accept {
+        // The ban this appeal was filed against must still be in force
+        let existing = storage::get_expert_record(env, expert);
+        if existing.status != ExpertStatus::Banned || existing.updated_at != appeal.banned_at {
+            return Err(RegistryError::NotBanned);
+        }
+        appeal.status = AppealStatus::Accepted;
+
+        // Undo the ban only: an expert who was never verified goes back to Unverified
+        let restored = match history::status_before_ban(env, expert) {
+            ExpertStatus::Verified | ExpertStatus::Suspended | ExpertStatus::Expired => {
+                ExpertStatus::Verified
+            }
+            _ => ExpertStatus::Unverified,
+        };
+
+        if restored == ExpertStatus::Verified {
+            // Same requirements as a fresh verification; the ban slashed the bond
+            uri::validate(env, &existing.data_uri)?;
+            bond::ensure_bonded(env, expert)?;
+            attestations::ensure_attested(env, expert)?;
+        }
+
+        // Suspension and expiry dates carry over, so they resume where the ban left them
+        let mut reinstated = existing.clone();
+        reinstated.status = restored;
+        reinstated.updated_at = env.ledger().timestamp();
+        storage::save_expert_record(env, expert, &reinstated);
+        if restored == ExpertStatus::Verified {
+            storage::add_expert_to_index(env, expert);
+            skills::index_expert(env, expert, &existing.skills);
+            sbt::mint(env, expert);
+        }
+
+        record_status_change(
+            env,
+            expert.clone(),
+            existing.status,
+            storage::get_expert_status(env, expert),
+            caller.clone(),
+            0,
+        );
+    } else {
+        appeal.status = AppealStatus::Rejected;
+    }
+
+    storage::set_appeal(env, expert, &appeal);
+    events::emit_appeal_resolved(env, expert.clone(), accept, caller.clone());
+    Ok(())
+}
+
+/// Get the latest appeal filed by an expert
+pub fn get_appeal(env: &Env, expert: &Address) -> Result<Appeal, RegistryError> {
+    storage::get_appeal(env, expert).ok_or(RegistryError::AppealNotFound)
+}
+
+/// Queue a request to be verified (the applicant only)
+pub fn apply_for_verification(
+    env: &Env,
+    expert: &Address,
+    data_uri: String,
+    data_hash: BytesN<32>,
+) -> Result<(), RegistryError> {
+    ensure_not_paused(env, PauseGroup::Verification)?;
+    expert.require_auth();
+
+    uri::validate(env, &data_uri)?;
+
+    match storage::get_expert_status(env, expert) {
+        ExpertStatus::Verified | ExpertStatus::Suspended => {
+            return Err(RegistryError::AlreadyVerified)
+        }
+        // Banned experts go through the appeal flow instead
+        ExpertStatus::Banned => return Err(RegistryError::AlreadyBanned),
+        // The expert lives on at another address
+        ExpertStatus::Migrated => return Err(RegistryError::AlreadyMigrated),
+        ExpertStatus::Unverified | ExpertStatus::Expired => {}
+    }
+
+    if let Some(existing) = storage::get_application(env, expert) {
+        if existing.status == ApplicationStatus::Pending {
+            return Err(RegistryError::ApplicationPending);
+        }
+    }
+
+    let application = Application {
+        data_uri: data_uri.clone(),
+        data_hash,
+        status: ApplicationStatus::Pending,
+        applied_at: env.ledger().timestamp(),
+        resolved_at: None,
+        reason_code: 0,
+    };
+    storage::set_application(env, expert, &application);
+    storage::add_pending_application(env, expert);
+
+    events::emit_application_submitted(env, expert.clone(), data_uri);
+    Ok(())
+}
+
+/// Approve a pending application by verifying the applicant with the profile they submitted (Verifier only)
+pub fn approve_application(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+) -> Result<(), RegistryError> {
+    let application = get_pending_application(env, expert)?;
+
+    // verify_expert checks the role and closes the application
+    verify_expert(
+        env,
+        caller,
+        expert,
+        application.data_uri,
+        application.data_hash,
+    )
+}
+
+/// Reject a pending application with a reason code (Verifier only)
+pub fn reject_application(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    reason_code: u32,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Verifier)?;
+    get_pending_application(env, expert)?;
+
+    close_application(env, expert, ApplicationStatus::Rejected, reason_code);
+    Ok(())
+}
+
+/// Get the latest application filed by an expert
+pub fn get_application(env: &Env, expert: &Address) -> Result<Application, RegistryError> {
+    storage::get_application(env, expert).ok_or(RegistryError::ApplicationNotFound)
+}
+
+/// Page through the applications awaiting review.
+/// `limit` is clamped to 1..=MAX_PAGE_SIZE.
+pub fn list_applications(env: &Env, start: u64, limit: u32) -> ApplicationPage {
+    let total = storage::get_pending_application_count(env);
+    let (indexes, next_cursor) = page(total, start, limit);
+
+    let mut applications = Vec::new(env);
+    for index in indexes {
+        let Some(expert) = storage::get_pending_application_by_index(env, index) else {
+            continue;
+        };
+        if let Some(application) = storage::get_application(env, &expert) {
+            applications.push_back((expert, application));
+        }
+    }
+
+    ApplicationPage {
+        applications,
+        next_cursor,
+    }
+}
+
+fn get_pending_application(env: &Env, expert: &Address) -> Result<Application, RegistryError> {
+    match storage::get_application(env, expert) {
+        Some(application) if application.status == ApplicationStatus::Pending => Ok(application),
+        _ => Err(RegistryError::ApplicationNotFound),
+    }
+}
+
+/// Settle the expert's pending application, if any, and take it off the review queue
+fn close_application(env: &Env, expert: &Address, status: ApplicationStatus, reason_code: u32) {
+    let Ok(mut application) = get_pending_application(env, expert) else {
+        return;
+    };
+
+    application.status = status;
+    application.resolved_at = Some(env.ledger().timestamp());
+    application.reason_code = reason_code;
+    storage::set_application(env, expert, &application);
+    storage::remove_pending_application(env, expert);
+
+    events::emit_application_resolved(
+        env,
+        expert.clone(),
+        status == ApplicationStatus::Approved,
+        reason_code,
     );
+}
+
+/// Configure the verification bond (SuperAdmin only)
+pub fn set_bond_config(
+    env: &Env,
+    caller: &Address,
+    config: BondConfig,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
 
+    bond::configure(env, &config)?;
+    events::emit_bond_config(env, config, caller.clone());
     Ok(())
 }
 
+/// Get the verification bond configuration
+pub fn get_bond_config(env: &Env) -> Result<BondConfig, RegistryError> {
+    storage::get_bond_config(env).ok_or(RegistryError::BondNotConfigured)
+}
+
+/// Lock tokens into the expert's bond (the expert only)
+pub fn deposit_bond(env: &Env, expert: &Address, amount: i128) -> Result<(), RegistryError> {
+    expert.require_auth();
+
+    if storage::get_expert_status(env, expert) == ExpertStatus::Migrated {
+        return Err(RegistryError::AlreadyMigrated);
+    }
+    bond::deposit(env, expert, amount)
+}
+
+/// Withdraw the expert's bond and leave the registry (the expert only).
+/// While a bond is required, a verified expert returns to Unverified.
+pub fn withdraw_bond(env: &Env, expert: &Address) -> Result<i128, RegistryError> {
+    expert.require_auth();
+
+    let existing = storage::get_expert_record(env, expert);
+    let status = storage::effective_status(env, &existing);
+    let amount = bond::withdraw(env, expert)?;
+
+    // Lapsed verifications are still stored as Verified, with their token and tags
+    if existing.status == ExpertStatus::Verified && bond::is_required(env) {
+        storage::set_expert_record(
+            env,
+            expert,
+            ExpertStatus::Unverified,
+            existing.data_uri,
+            existing.data_hash,
+        );
+        skills::unindex_expert(env, expert, &existing.skills);
+        sbt::burn(env, expert);
+
+        record_status_change(
+            env,
+            expert.clone(),
+            status,
+            ExpertStatus::Unverified,
+            expert.clone(),
+            0,
+        );
+    }
+
+    Ok(amount)
+}
+
+/// Set how long new and renewed verifications stay valid, 0 for forever (SuperAdmin only).
+/// Existing expiry dates are left as they are; verifications without one lapse
+/// a period after they were last verified or renewed.
+pub fn set_verification_period(
+    env: &Env,
+    caller: &Address,
+    period: u64,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+    council::ensure_inactive(env)?;
+
+    apply_set_verification_period(env, caller, period);
+    Ok(())
+}
+
+pub(crate) fn apply_set_verification_period(env: &Env, caller: &Address, period: u64) {
+    storage::set_verification_period(env, period);
+    events::emit_verification_period(env, period, caller.clone());
+}
+
+/// Get how long a verification stays valid, in seconds (0 = forever)
+pub fn get_verification_period(env: &Env) -> u64 {
+    storage::get_verification_period(env)
+}
+
+/// Restart the expert's verification period from now (Verifier only).
+/// Works on lapsed verifications too; never-verified experts must be verified instead
+/// and banned ones reinstated on appeal.
+pub fn renew_verification(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+) -> Result<u64, RegistryError> {
+    require_role(env, caller, Role::Verifier)?;
+
+    if storage::get_expert_record(env, expert).status != ExpertStatus::Verified {
+        return Err(RegistryError::NotVerified);
+    }
+    bond::ensure_bonded(env, expert)?;
+
+    let verified_until = start_verification_period(env, expert);
+    events::emit_verification_renewed(env, expert.clone(), verified_until, caller.clone());
+    Ok(verified_until)
+}
+
+/// Page through the directory for verified experts whose verification lapses
+/// before `before_ts`, including those that already have.
+/// `limit` bounds the number of index slots scanned (clamped to 1..=MAX_PAGE_SIZE).
+pub fn list_expiring(env: &Env, before_ts: u64, start: u64, limit: u32) -> ExpertPage {
+    let total = storage::get_total_experts(env);
+    let (indexes, next_cursor) = page(total, start, limit);
+
+    let mut experts = Vec::new(env);
+    for index in indexes {
+        let Some(expert) = storage::get_expert_by_index(env, index) else {
+            continue;
+        };
+        let mut record = storage::get_expert_record(env, &expert);
+        let expires_at = storage::verification_expiry(env, &record);
+        if record.status == ExpertStatus::Verified && expires_at != 0 && expires_at < before_ts {
+            record.status = storage::effective_status(env, &record);
+            experts.push_back((expert, record));
+        }
+    }
+
+    ExpertPage {
+        experts,
+        next_cursor,
+    }
+}
+
+/// Report how a single batch item went
+fn batch_result(expert: Address, outcome: Result<(), RegistryError>) -> BatchResult {
+    match outcome {
+        Ok(()) => BatchResult {
+            expert,
+            success: true,
+            error_code: 0,
+        },
+        Err(err) => BatchResult {
+            expert,
+            success: false,
+            error_code: err as u32,
+        },
+    }
+}
+
+/// The hash stored for profiles registered without one
+fn empty_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0u8; 32])
+}
+
+/// Log a status transition to the expert's history and emit it
+fn record_status_change(
+    env: &Env,
+    expert: Address,
+    old_status: ExpertStatus,
+    new_status: ExpertStatus,
+    actor: Address,
+    reason_code: u32,
+) {
+    history::record(env, &expert, old_status, new_status, &actor, reason_code);
+    events::emit_status_change(env, expert, old_status, new_status, actor, reason_code);
+}
+
+/// Stamp the verification time and a fresh verified_until on the expert's record and return the latter
+fn start_verification_period(env: &Env, expert: &Address) -> u64 {
+    let period = storage::get_verification_period(env);
+    let mut record = storage::get_expert_record(env, expert);
+    let now = env.ledger().timestamp();
+    record.verified_at = now;
+    record.verified_until = if period == 0 {
+        0
+    } else {
+        now.saturating_add(period)
+    };
+    storage::save_expert_record(env, expert, &record);
+    record.verified_until
+}
+
+/// Attest to a claim about an expert (Attester only)
+pub fn attest(
+    env: &Env,
+    attester: &Address,
+    expert: &Address,
+    claim_type: Symbol,
+    claim_hash: BytesN<32>,
+    expires_at: u64,
+) -> Result<(), RegistryError> {
+    require_role(env, attester, Role::Attester)?;
+
+    attestations::attest(
+        env,
+        attester,
+        expert,
+        claim_type.clone(),
+        claim_hash.clone(),
+        expires_at,
+    )?;
+    events::emit_attested(
+        env,
+        expert.clone(),
+        attester.clone(),
+        claim_type,
+        claim_hash,
+        expires_at,
+    );
+    Ok(())
+}
+
+/// Revoke an attestation (the attester who made it, or a Moderator)
+pub fn revoke_attestation(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    attester: &Address,
+    claim_type: Symbol,
+) -> Result<(), RegistryError> {
+    if caller == attester {
+        caller.require_auth();
+    } else {
+        require_role(env, caller, Role::Moderator)?;
+    }
+
+    attestations::revoke(env, attester, expert, &claim_type)?;
+    events::emit_attestation_revoked(
+        env,
+        expert.clone(),
+        attester.clone(),
+        claim_type,
+        caller.clone(),
+    );
+    Ok(())
+}
+
+/// Get every attestation recorded for an expert, revoked and expired ones included
+pub fn get_attestations(env: &Env, expert: &Address) -> Vec<Attestation> {
+    storage::get_attestations(env, expert)
+}
+
+/// Set the attestations verification requires; `required` of zero disables the check (SuperAdmin only)
+pub fn set_attestation_policy(
+    env: &Env,
+    caller: &Address,
+    policy: AttestationPolicy,
+) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+
+    storage::set_attestation_policy(env, &policy);
+    events::emit_attestation_policy(env, policy, caller.clone());
+    Ok(())
+}
+
+/// Set the policy every stored URI must satisfy (SuperAdmin only)
+pub fn set_uri_policy(env: &Env, caller: &Address, policy: UriPolicy) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+    uri::validate_policy(&policy)?;
+
+    storage::set_uri_policy(env, &policy);
+    events::emit_uri_policy(env, policy, caller.clone());
+    Ok(())
+}
+
+/// Get the URI policy in force
+pub fn get_uri_policy(env: &Env) -> UriPolicy {
+    uri::policy(env)
+}
+
+/// Stop a group of entrypoints in an emergency (Guardian only)
+pub fn pause(env: &Env, caller: &Address, group: PauseGroup) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Guardian)?;
+
+    storage::set_paused(env, group, true);
+    events::emit_paused(env, group, caller.clone());
+    Ok(())
+}
+
+/// Resume a paused group of entrypoints (Guardian only)
+pub fn unpause(env: &Env, caller: &Address, group: PauseGroup) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::Guardian)?;
+
+    storage::set_paused(env, group, false);
+    events::emit_unpaused(env, group, caller.clone());
+    Ok(())
+}
+
+/// Check whether a group of entrypoints is paused
+pub fn is_paused(env: &Env, group: PauseGroup) -> bool {
+    storage::is_paused(env, group)
+}
+
+/// Fail while the group is paused
+fn ensure_not_paused(env: &Env, group: PauseGroup) -> Result<(), RegistryError> {
+    if storage::is_paused(env, group) {
+        return Err(RegistryError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Get the attestations verification requires
+pub fn get_attestation_policy(env: &Env) -> Option<AttestationPolicy> {
+    storage::get_attestation_policy(env)
+}
+
+/// Install the first council (SuperAdmin only). Later changes go through a proposal.
+pub fn set_council(env: &Env, caller: &Address, new_council: Council) -> Result<(), RegistryError> {
+    require_role(env, caller, Role::SuperAdmin)?;
+    council::ensure_inactive(env)?;
+
+    council::apply_set_council(env, caller, new_council)?;
+    // A transfer the admin proposed alone must not complete under the council
+    storage::remove_pending_admin(env);
+    Ok(())
+}
+
+/// Get the current council, if one is installed
+pub fn get_council(env: &Env) -> Option<Council> {
+    storage::get_council(env)
+}
+
+/// Open a proposal for a sensitive action (council members only)
+pub fn propose(env: &Env, proposer: &Address, action: CouncilAction) -> Result<u64, RegistryError> {
+    council::propose(env, proposer, action)
+}
+
+/// Approve an open proposal (council members only)
+pub fn approve_proposal(
+    env: &Env,
+    signer: &Address,
+    proposal_id: u64,
+) -> Result<(), RegistryError> {
+    council::approve(env, signer, proposal_id)
+}
+
+/// Run a proposal that has reached the threshold (council members only)
+pub fn execute_proposal(
+    env: &Env,
+    caller: &Address,
+    proposal_id: u64,
+) -> Result<(), RegistryError> {
+    council::execute(env, caller, proposal_id)
+}
+
+/// Get a proposal by id
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, RegistryError> {
+    storage::get_proposal(env, proposal_id).ok_or(RegistryError::ProposalNotFound)
+}
+
 /// Get the total number of verified experts ever indexed
 pub fn get_total_experts(env: &Env) -> u64 {
     storage::get_total_experts(env)
 }
 
 /// Get the expert address at the given index
-pub fn get_expert_by_index(env: &Env, index: u64) -> Address {
-    storage::get_expert_by_index(env, index)
+pub fn get_expert_by_index(env: &Env, index: u64) -> Result<Address, RegistryError> {
+    storage::get_expert_by_index(env, index).ok_or(RegistryError::ExpertNotFound)
+}
+
+/// Page through the directory starting at index `start`.
+/// `limit` bounds the number of index slots scanned (clamped to 1..=MAX_PAGE_SIZE),
+/// so a filtered page may hold fewer entries even when more follow.
+pub fn list_experts(
+    env: &Env,
+    start: u64,
+    limit: u32,
+    status_filter: Option<ExpertStatus>,
+) -> ExpertPage {
+    let total = storage::get_total_experts(env);
+    let (indexes, next_cursor) = page(total, start, limit);
+
+    let mut experts = Vec::new(env);
+    for index in indexes {
+        let Some(expert) = storage::get_expert_by_index(env, index) else {
+            continue;
+        };
+        let mut record = storage::get_expert_record(env, &expert);
+        record.status = storage::effective_status(env, &record);
+        if status_filter.is_none_or(|status| record.status == status) {
+            experts.push_back((expert, record));
+        }
+    }
+
+    ExpertPage {
+        experts,
+        next_cursor,
+    }
 }
 
 /// Get the current status of an expert
@@ -132,20 +1059,45 @@ pub fn get_expert_status(env: &Env, expert: &Address) -> ExpertStatus {
     storage::get_expert_status(env, expert)
 }
 
+/// Get a page of the expert's status history, oldest first
+pub fn get_status_history(
+    env: &Env,
+    expert: &Address,
+    start: u64,
+    limit: u32,
+) -> StatusHistoryPage {
+    let history = storage::get_status_history(env, expert);
+    let total = history.len() as u64;
+    let (indexes, next_cursor) = page(total, start, limit);
+
+    let mut entries = Vec::new(env);
+    for index in indexes {
+        entries.push_back(history.get(index as u32).unwrap());
+    }
+
+    StatusHistoryPage {
+        entries,
+        next_cursor,
+    }
+}
+
 /// Check if an expert is verified
-/// Returns true only if the expert's status is Verified
+/// Returns true only if the expert's status is Verified, not under an active suspension and not lapsed
 pub fn is_verified(env: &Env, expert: &Address) -> bool {
     storage::get_expert_status(env, expert) == ExpertStatus::Verified
 }
 
 /// Allow a verified expert to update their own profile URI
-pub fn update_profile(env: &Env, expert: &Address, new_uri: String) -> Result<(), RegistryError> {
+pub fn update_profile(
+    env: &Env,
+    expert: &Address,
+    new_uri: String,
+    new_hash: BytesN<32>,
+) -> Result<(), RegistryError> {
+    ensure_not_paused(env, PauseGroup::Profiles)?;
     expert.require_auth();
 
-    // Validate URI length
-    if new_uri.len() > 64 {
-        return Err(RegistryError::UriTooLong);
-    }
+    uri::validate(env, &new_uri)?;
 
     let status = storage::get_expert_status(env, expert);
     if status != ExpertStatus::Verified {
@@ -153,7 +1105,231 @@ pub fn update_profile(env: &Env, expert: &Address, new_uri: String) -> Result<()
     }
 
     // Update record preserving status
-    storage::set_expert_record(env, expert, status, new_uri.clone());
-    events::emit_profile_updated(env, expert.clone(), new_uri);
+    storage::set_expert_record(env, expert, status, new_uri.clone(), new_hash.clone());
+    events::emit_profile_updated(env, expert.clone(), new_uri, new_hash);
+    Ok(())
+}
+
+/// Claim a unique handle for a verified expert (the expert only).
+/// Claiming again swaps the old handle for the new one.
+pub fn claim_handle(env: &Env, expert: &Address, handle: String) -> Result<(), RegistryError> {
+    ensure_not_paused(env, PauseGroup::Profiles)?;
+    expert.require_auth();
+
+    if storage::get_expert_status(env, expert) != ExpertStatus::Verified {
+        return Err(RegistryError::NotVerified);
+    }
+
+    let handle = handles::normalize(env, &handle)?;
+    handles::claim(env, expert, &handle)?;
+    events::emit_handle_claimed(env, expert.clone(), handle);
+    Ok(())
+}
+
+/// Get the expert holding a handle, ignoring case
+pub fn resolve_handle(env: &Env, handle: &String) -> Result<Address, RegistryError> {
+    handles::resolve(env, handle)
+}
+
+/// Get the handle an expert holds, if any
+pub fn get_handle(env: &Env, expert: &Address) -> Option<String> {
+    storage::get_expert_record(env, expert).handle
+}
+
+/// Move an expert to a new address, signed by both the old and the new key
+pub fn migrate_identity(env: &Env, old: &Address, new: &Address) -> Result<(), IdentityError> {
+    if storage::is_paused(env, PauseGroup::Profiles) {
+        return Err(IdentityError::ContractPaused);
+    }
+    old.require_auth();
+    new.require_auth();
+
+    identity::ensure_migratable(env, old, new)?;
+    apply_migration(env, old, new, old);
+    Ok(())
+}
+
+/// Start recovering an expert whose key was lost (SuperAdmin only).
+/// Returns the ledger timestamp the recovery can be executed from.
+pub fn request_recovery(
+    env: &Env,
+    caller: &Address,
+    old: &Address,
+    new: &Address,
+) -> Result<u64, IdentityError> {
+    require_recovery_admin(env, caller)?;
+    ensure_recovery_approved(env, old, new)?;
+    identity::ensure_migratable(env, old, new)?;
+
+    let now = env.ledger().timestamp();
+    let executable_at = now.saturating_add(RECOVERY_TIMELOCK);
+    let request = RecoveryRequest {
+        new_address: new.clone(),
+        requested_by: caller.clone(),
+        requested_at: now,
+        executable_at,
+    };
+    storage::set_recovery(env, old, &request);
+    events::emit_recovery_requested(env, old.clone(), new.clone(), executable_at, caller.clone());
+    Ok(executable_at)
+}
+
+/// Cancel a pending recovery (the expert's old key or a SuperAdmin)
+pub fn cancel_recovery(env: &Env, caller: &Address, old: &Address) -> Result<(), IdentityError> {
+    if caller == old {
+        caller.require_auth();
+    } else {
+        require_recovery_admin(env, caller)?;
+    }
+
+    if storage::get_recovery(env, old).is_none() {
+        return Err(IdentityError::RecoveryNotFound);
+    }
+    storage::remove_recovery(env, old);
+    storage::remove_recovery_approval(env, old);
+    events::emit_recovery_cancelled(env, old.clone(), caller.clone());
+    Ok(())
+}
+
+/// Run a pending recovery once its timelock has passed (SuperAdmin only)
+pub fn execute_recovery(env: &Env, caller: &Address, old: &Address) -> Result<(), IdentityError> {
+    if storage::is_paused(env, PauseGroup::Profiles) {
+        return Err(IdentityError::ContractPaused);
+    }
+    require_recovery_admin(env, caller)?;
+
+    let request = storage::get_recovery(env, old).ok_or(IdentityError::RecoveryNotFound)?;
+    if env.ledger().timestamp() < request.executable_at {
+        return Err(IdentityError::RecoveryTimelocked);
+    }
+    ensure_recovery_approved(env, old, &request.new_address)?;
+    identity::ensure_migratable(env, old, &request.new_address)?;
+
+    apply_migration(env, old, &request.new_address, caller);
+    Ok(())
+}
+
+/// Get the pending recovery for an expert, if any
+pub fn get_recovery(env: &Env, old: &Address) -> Option<RecoveryRequest> {
+    storage::get_recovery(env, old)
+}
+
+/// Get the address a migrated expert moved to
+pub fn get_migrated_to(env: &Env, old: &Address) -> Option<Address> {
+    storage::get_migrated_to(env, old)
+}
+
+// An uninitialized registry has no SuperAdmin, so both failures read as MissingRole
+fn require_recovery_admin(env: &Env, caller: &Address) -> Result<(), IdentityError> {
+    require_role(env, caller, Role::SuperAdmin).map_err(|_| IdentityError::MissingRole)
+}
+
+/// Once a council is installed, a recovery also needs a `Recover` proposal for the same address
+fn ensure_recovery_approved(env: &Env, old: &Address, new: &Address) -> Result<(), IdentityError> {
+    if storage::get_council(env).is_some()
+        && storage::get_recovery_approval(env, old).as_ref() != Some(new)
+    {
+        return Err(IdentityError::CouncilRequired);
+    }
+    Ok(())
+}
+
+fn apply_migration(env: &Env, old: &Address, new: &Address, actor: &Address) {
+    let status = storage::get_expert_status(env, old);
+
+    close_application(env, old, ApplicationStatus::Rejected, 0);
+    storage::remove_recovery(env, old);
+    storage::remove_recovery_approval(env, old);
+    identity::migrate(env, old, new);
+
+    record_status_change(
+        env,
+        old.clone(),
+        status,
+        ExpertStatus::Migrated,
+        actor.clone(),
+        0,
+    );
+    events::emit_identity_migrated(env, old.clone(), new.clone(), actor.clone());
+}
+
+/// Check a profile document against the hash the expert registered
+/// Returns false for experts without a record and for the placeholder stored by batch adds
+pub fn verify_profile_hash(env: &Env, expert: &Address, hash: &BytesN<32>) -> bool {
+    *hash != empty_hash(env)
+        && storage::has_expert_record(env, expert)
+        && storage::get_expert_record(env, expert).data_hash == *hash
+}
+
+/// Replace a verified expert's skill tags (the expert themselves or a Verifier)
+pub fn set_skills(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    skills: Vec<Symbol>,
+) -> Result<(), RegistryError> {
+    ensure_not_paused(env, PauseGroup::Profiles)?;
+
+    if caller == expert {
+        caller.require_auth();
+    } else {
+        require_role(env, caller, Role::Verifier)?;
+    }
+
+    skills::validate(&skills)?;
+
+    let mut record = storage::get_expert_record(env, expert);
+    if storage::effective_status(env, &record) != ExpertStatus::Verified {
+        return Err(RegistryError::NotVerified);
+    }
+
+    skills::unindex_expert(env, expert, &record.skills);
+    record.skills = skills.clone();
+    record.updated_at = env.ledger().timestamp();
+    storage::save_expert_record(env, expert, &record);
+    skills::index_expert(env, expert, &record.skills);
+
+    events::emit_skills_updated(env, expert.clone(), skills, caller.clone());
     Ok(())
 }
+
+/// Page through the experts listed under a skill tag.
+/// `limit` is clamped to 1..=MAX_PAGE_SIZE.
+pub fn get_experts_by_skill(env: &Env, tag: &Symbol, start: u64, limit: u32) -> ExpertPage {
+    let total = storage::get_skill_count(env, tag);
+    let (indexes, next_cursor) = page(total, start, limit);
+
+    let mut experts = Vec::new(env);
+    for index in indexes {
+        if let Some(expert) = storage::get_expert_by_skill_index(env, tag, index) {
+            let mut record = storage::get_expert_record(env, &expert);
+            record.status = storage::effective_status(env, &record);
+            experts.push_back((expert, record));
+        }
+    }
+
+    ExpertPage {
+        experts,
+        next_cursor,
+    }
+}
+
+/// Get the number of verification tokens held by an address (0 or 1)
+pub fn balance(env: &Env, owner: &Address) -> u32 {
+    sbt::balance(env, owner)
+}
+
+/// Get the owner of a verification token
+pub fn owner_of(env: &Env, token_id: u64) -> Result<Address, RegistryError> {
+    sbt::owner_of(env, token_id)
+}
+
+/// Get the verification token held by an expert
+pub fn token_of(env: &Env, owner: &Address) -> Result<u64, RegistryError> {
+    sbt::token_of(env, owner)
+}
+
+/// Get the metadata URI of a verification token (the owner's profile data_uri)
+pub fn token_uri(env: &Env, token_id: u64) -> Result<String, RegistryError> {
+    sbt::token_uri(env, token_id)
+}
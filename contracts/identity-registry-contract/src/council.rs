@@ -0,0 +1,170 @@
+use crate::contract;
+use crate::error::RegistryError;
+use crate::events;
+use crate::storage;
+use crate::types::{Council, CouncilAction, Proposal};
+use soroban_sdk::{Address, Env, Vec};
+
+// Council
+//
+// Once a council is installed, bans, suspensions, role changes, admin
+// transfers, key recoveries, verification period changes, upgrades and
+// changes to the council itself stop being single-signer: a member proposes
+// the action, other members approve it, and any member executes it once
+// `threshold` current members have approved and the proposal has not expired.
+
+/// Maximum number of council signers
+pub const MAX_SIGNERS: u32 = 10;
+
+/// Fail if a council is installed and the action must go through a proposal
+pub fn ensure_inactive(env: &Env) -> Result<(), RegistryError> {
+    if storage::get_council(env).is_some() {
+        return Err(RegistryError::CouncilRequired);
+    }
+    Ok(())
+}
+
+/// Validate and install a council; an empty council with a zero threshold removes it
+pub fn apply_set_council(
+    env: &Env,
+    set_by: &Address,
+    council: Council,
+) -> Result<(), RegistryError> {
+    if council.signers.is_empty() && council.threshold == 0 {
+        storage::remove_council(env);
+        events::emit_council_set(env, council, set_by.clone());
+        return Ok(());
+    }
+
+    let size = council.signers.len();
+    if size > MAX_SIGNERS
+        || council.threshold == 0
+        || council.threshold > size
+        || council.proposal_ttl == 0
+    {
+        return Err(RegistryError::InvalidCouncil);
+    }
+    for (i, signer) in council.signers.iter().enumerate() {
+        if council
+            .signers
+            .iter()
+            .skip(i + 1)
+            .any(|other| other == signer)
+        {
+            return Err(RegistryError::InvalidCouncil);
+        }
+    }
+
+    storage::set_council(env, &council);
+    events::emit_council_set(env, council, set_by.clone());
+    Ok(())
+}
+
+/// Open a proposal; the proposer's approval is included
+pub fn propose(env: &Env, proposer: &Address, action: CouncilAction) -> Result<u64, RegistryError> {
+    let council = require_member(env, proposer)?;
+
+    let now = env.ledger().timestamp();
+    let proposal = Proposal {
+        id: storage::get_next_proposal_id(env),
+        proposer: proposer.clone(),
+        action,
+        approvals: Vec::from_array(env, [proposer.clone()]),
+        created_at: now,
+        expires_at: now.saturating_add(council.proposal_ttl),
+        executed: false,
+    };
+    storage::set_proposal(env, &proposal);
+
+    events::emit_proposal_created(env, proposal.id, proposer.clone());
+    Ok(proposal.id)
+}
+
+/// Add a member's approval to an open proposal
+pub fn approve(env: &Env, signer: &Address, proposal_id: u64) -> Result<(), RegistryError> {
+    require_member(env, signer)?;
+
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+    if proposal.approvals.contains(signer) {
+        return Err(RegistryError::AlreadyApproved);
+    }
+    proposal.approvals.push_back(signer.clone());
+    storage::set_proposal(env, &proposal);
+
+    events::emit_proposal_approved(env, proposal_id, signer.clone());
+    Ok(())
+}
+
+/// Run a proposal that current members have approved up to the threshold
+pub fn execute(env: &Env, caller: &Address, proposal_id: u64) -> Result<(), RegistryError> {
+    let council = require_member(env, caller)?;
+
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+
+    // Approvals from signers removed since the proposal opened no longer count
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|signer| council.signers.contains(signer))
+        .count() as u32;
+    if approvals < council.threshold {
+        return Err(RegistryError::ThresholdNotMet);
+    }
+
+    proposal.executed = true;
+    storage::set_proposal(env, &proposal);
+
+    match proposal.action {
+        CouncilAction::Ban(expert) => contract::apply_ban(env, caller, &expert)?,
+        CouncilAction::BatchBan(experts) => {
+            if experts.len() > 20 {
+                return Err(RegistryError::ExpertVecMax);
+            }
+            for expert in experts {
+                contract::apply_ban(env, caller, &expert)?;
+            }
+        }
+        CouncilAction::Suspend(expert, duration, reason_code) => {
+            contract::apply_suspend(env, caller, &expert, duration, reason_code)?
+        }
+        CouncilAction::GrantRole(role, account) => {
+            contract::apply_grant_role(env, caller, role, &account)
+        }
+        CouncilAction::RevokeRole(role, account) => {
+            contract::apply_revoke_role(env, caller, role, &account)
+        }
+        CouncilAction::Upgrade(wasm_hash) => contract::apply_upgrade(env, wasm_hash, caller),
+        CouncilAction::SetCouncil(new_council) => apply_set_council(env, caller, new_council)?,
+        CouncilAction::TransferAdmin(new_admin) => contract::apply_transfer_admin(env, &new_admin)?,
+        CouncilAction::Recover(old, new) => storage::set_recovery_approval(env, &old, &new),
+        CouncilAction::SetVerificationPeriod(period) => {
+            contract::apply_set_verification_period(env, caller, period)
+        }
+    }
+
+    events::emit_proposal_executed(env, proposal_id, caller.clone());
+    Ok(())
+}
+
+/// Require the signer's authorization and membership of the current council
+fn require_member(env: &Env, signer: &Address) -> Result<Council, RegistryError> {
+    signer.require_auth();
+
+    let council = storage::get_council(env).ok_or(RegistryError::NotCouncilMember)?;
+    if !council.signers.contains(signer) {
+        return Err(RegistryError::NotCouncilMember);
+    }
+    Ok(council)
+}
+
+fn get_open_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, RegistryError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(RegistryError::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(RegistryError::ProposalAlreadyExecuted);
+    }
+    if env.ledger().timestamp() >= proposal.expires_at {
+        return Err(RegistryError::ProposalExpired);
+    }
+    Ok(proposal)
+}
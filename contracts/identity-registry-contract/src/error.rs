@@ -10,6 +10,13 @@ pub enum RegistryError {
 
     // Authorization Errors
     AdminOnly = 3,
+    MissingRole = 10,
+    NoPendingAdmin = 11,
+    AlreadyMigrated = 12,
+
+    // Soulbound Token Errors
+    TokenNotFound = 13,
+    SoulboundNonTransferable = 14,
 
     // Logic Errors
     ExpertNotFound = 4,
@@ -18,4 +25,72 @@ pub enum RegistryError {
     ExpertVecMax = 7,
     NotVerified = 8,
     UriTooLong = 9,
+    TooManySkills = 15,
+    DuplicateSkill = 16,
+    InvalidDuration = 17,
+
+    // Appeal Errors
+    NotBanned = 18,
+    AppealAlreadyFiled = 19,
+    AppealNotFound = 20,
+    AppealAlreadyResolved = 21,
+
+    // Application Errors
+    ApplicationPending = 22,
+    ApplicationNotFound = 23,
+
+    // Bond Errors
+    BondRequired = 24,
+    BondNotConfigured = 25,
+    InvalidBondConfig = 26,
+    InvalidBondAmount = 27,
+    NoBond = 28,
+    BondCooldown = 29,
+    ExpertSuspended = 30,
+    BondsOutstanding = 31,
+
+    // Attestation Errors
+    TooManyAttestations = 32,
+    AttestationNotFound = 33,
+    InvalidExpiry = 34,
+    NotEnoughAttestations = 35,
+
+    // Council Errors
+    CouncilRequired = 36,
+    NotCouncilMember = 37,
+    ProposalNotFound = 38,
+    ProposalExpired = 39,
+    AlreadyApproved = 40,
+    ThresholdNotMet = 41,
+    ProposalAlreadyExecuted = 42,
+    InvalidCouncil = 43,
+
+    // URI Policy Errors
+    UriSchemeNotAllowed = 44,
+    UriRequired = 45,
+    InvalidUriPolicy = 46,
+
+    // Pause Errors
+    ContractPaused = 47,
+
+    // Handle Errors
+    InvalidHandle = 48,
+    HandleTaken = 49, // Held by another expert or still in quarantine
+    HandleNotFound = 50,
+}
+
+/// Errors for identity migration. `RegistryError` is full, since a contract
+/// error enum holds at most 50 variants, so codes shared with it are repeated here.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdentityError {
+    ExpertNotFound = 4,
+    MissingRole = 10,
+    AlreadyMigrated = 12,
+    CouncilRequired = 36,
+    ContractPaused = 47,
+    IdentityInUse = 51,
+    RecoveryNotFound = 52,
+    RecoveryTimelocked = 53,
 }
@@ -1,5 +1,7 @@
-use crate::types::ExpertStatus;
-use soroban_sdk::{contracttype, Address, Env, Symbol, String};
+use crate::types::{
+    AttestationPolicy, BondConfig, Council, ExpertStatus, PauseGroup, Role, UriPolicy,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 
 // The Event Data Structure
 #[contracttype]
@@ -9,6 +11,7 @@ pub struct ExpertStatusChangedEvent {
     pub old_status: ExpertStatus,
     pub new_status: ExpertStatus,
     pub admin: Address,
+    pub reason_code: u32, // Moderator-supplied reason, 0 when none was given
 }
 
 // Helper function to emit the status change event
@@ -19,12 +22,14 @@ pub fn emit_status_change(
     old_status: ExpertStatus,
     new_status: ExpertStatus,
     admin: Address,
+    reason_code: u32,
 ) {
     let event = ExpertStatusChangedEvent {
         expert,
         old_status,
         new_status,
         admin,
+        reason_code,
     };
 
     // published with the topic "status_change" so indexers can find it easily
@@ -32,17 +37,577 @@ pub fn emit_status_change(
         .publish((Symbol::new(env, "status_change"),), event);
 }
 
-// Event for profile URI updates
+// Event for profile URI and content hash updates
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProfileUpdatedEvent {
     pub expert: Address,
     pub new_uri: String,
+    pub new_hash: BytesN<32>,
 }
 
 #[allow(deprecated)]
-pub fn emit_profile_updated(env: &Env, expert: Address, new_uri: String) {
-    let event = ProfileUpdatedEvent { expert, new_uri };
+pub fn emit_profile_updated(env: &Env, expert: Address, new_uri: String, new_hash: BytesN<32>) {
+    let event = ProfileUpdatedEvent {
+        expert,
+        new_uri,
+        new_hash,
+    };
     env.events()
         .publish((Symbol::new(env, "profile_updated"),), event);
 }
+
+// Event for role changes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleChangedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub caller: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_role_granted(env: &Env, role: Role, account: Address, caller: Address) {
+    let event = RoleChangedEvent {
+        role,
+        account,
+        caller,
+    };
+    env.events()
+        .publish((Symbol::new(env, "role_granted"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_role_revoked(env: &Env, role: Role, account: Address, caller: Address) {
+    let event = RoleChangedEvent {
+        role,
+        account,
+        caller,
+    };
+    env.events()
+        .publish((Symbol::new(env, "role_revoked"),), event);
+}
+
+// Event for the two-step admin transfer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_admin_transfer_proposed(env: &Env, old_admin: Address, new_admin: Address) {
+    let event = AdminTransferEvent {
+        old_admin,
+        new_admin,
+    };
+    env.events()
+        .publish((Symbol::new(env, "admin_proposed"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_admin_transferred(env: &Env, old_admin: Address, new_admin: Address) {
+    let event = AdminTransferEvent {
+        old_admin,
+        new_admin,
+    };
+    env.events()
+        .publish((Symbol::new(env, "admin_transferred"),), event);
+}
+
+// Event for contract upgrades
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradedEvent {
+    pub new_wasm_hash: BytesN<32>,
+    pub admin: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_upgraded(env: &Env, new_wasm_hash: BytesN<32>, admin: Address) {
+    let event = UpgradedEvent {
+        new_wasm_hash,
+        admin,
+    };
+    env.events().publish((Symbol::new(env, "upgraded"),), event);
+}
+
+// Event for storage migrations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub records: u32,
+}
+
+#[allow(deprecated)]
+pub fn emit_migrated(env: &Env, from_version: u32, to_version: u32, records: u32) {
+    let event = MigratedEvent {
+        from_version,
+        to_version,
+        records,
+    };
+    env.events().publish((Symbol::new(env, "migrated"),), event);
+}
+
+// Event for soulbound token mint and burn
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SbtEvent {
+    pub token_id: u64,
+    pub owner: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_sbt_minted(env: &Env, token_id: u64, owner: Address) {
+    let event = SbtEvent { token_id, owner };
+    env.events()
+        .publish((Symbol::new(env, "sbt_minted"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_sbt_burned(env: &Env, token_id: u64, owner: Address) {
+    let event = SbtEvent { token_id, owner };
+    env.events()
+        .publish((Symbol::new(env, "sbt_burned"),), event);
+}
+
+// Event for skill tag changes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkillsUpdatedEvent {
+    pub expert: Address,
+    pub skills: Vec<Symbol>,
+    pub updated_by: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_skills_updated(env: &Env, expert: Address, skills: Vec<Symbol>, updated_by: Address) {
+    let event = SkillsUpdatedEvent {
+        expert,
+        skills,
+        updated_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "skills_updated"),), event);
+}
+
+// Events for ban appeals
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealFiledEvent {
+    pub expert: Address,
+    pub evidence_uri: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealResolvedEvent {
+    pub expert: Address,
+    pub accepted: bool,
+    pub resolved_by: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_appeal_filed(env: &Env, expert: Address, evidence_uri: String) {
+    let event = AppealFiledEvent {
+        expert,
+        evidence_uri,
+    };
+    env.events()
+        .publish((Symbol::new(env, "appeal_filed"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_appeal_resolved(env: &Env, expert: Address, accepted: bool, resolved_by: Address) {
+    let event = AppealResolvedEvent {
+        expert,
+        accepted,
+        resolved_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "appeal_resolved"),), event);
+}
+
+// Events for verification applications
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationSubmittedEvent {
+    pub expert: Address,
+    pub data_uri: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationResolvedEvent {
+    pub expert: Address,
+    pub approved: bool,
+    pub reason_code: u32,
+}
+
+#[allow(deprecated)]
+pub fn emit_application_submitted(env: &Env, expert: Address, data_uri: String) {
+    let event = ApplicationSubmittedEvent { expert, data_uri };
+    env.events()
+        .publish((Symbol::new(env, "application_submitted"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_application_resolved(env: &Env, expert: Address, approved: bool, reason_code: u32) {
+    let event = ApplicationResolvedEvent {
+        expert,
+        approved,
+        reason_code,
+    };
+    env.events()
+        .publish((Symbol::new(env, "application_resolved"),), event);
+}
+
+// Events for verification bonds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfigEvent {
+    pub config: BondConfig,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondEvent {
+    pub expert: Address,
+    pub amount: i128,
+    pub remaining: i128, // Bond left on the record afterwards
+}
+
+#[allow(deprecated)]
+pub fn emit_bond_config(env: &Env, config: BondConfig, admin: Address) {
+    let event = BondConfigEvent { config, admin };
+    env.events()
+        .publish((Symbol::new(env, "bond_config"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_bond_deposited(env: &Env, expert: Address, amount: i128, remaining: i128) {
+    let event = BondEvent {
+        expert,
+        amount,
+        remaining,
+    };
+    env.events()
+        .publish((Symbol::new(env, "bond_deposited"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_bond_slashed(env: &Env, expert: Address, amount: i128, remaining: i128) {
+    let event = BondEvent {
+        expert,
+        amount,
+        remaining,
+    };
+    env.events()
+        .publish((Symbol::new(env, "bond_slashed"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_bond_withdrawn(env: &Env, expert: Address, amount: i128, remaining: i128) {
+    let event = BondEvent {
+        expert,
+        amount,
+        remaining,
+    };
+    env.events()
+        .publish((Symbol::new(env, "bond_withdrawn"),), event);
+}
+
+// Events for verification expiry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationPeriodEvent {
+    pub period: u64,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationRenewedEvent {
+    pub expert: Address,
+    pub verified_until: u64,
+    pub renewed_by: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_verification_period(env: &Env, period: u64, admin: Address) {
+    let event = VerificationPeriodEvent { period, admin };
+    env.events()
+        .publish((Symbol::new(env, "verification_period"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_verification_renewed(
+    env: &Env,
+    expert: Address,
+    verified_until: u64,
+    renewed_by: Address,
+) {
+    let event = VerificationRenewedEvent {
+        expert,
+        verified_until,
+        renewed_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "verification_renewed"),), event);
+}
+
+// Events for attestations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestedEvent {
+    pub expert: Address,
+    pub attester: Address,
+    pub claim_type: Symbol,
+    pub claim_hash: BytesN<32>,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationRevokedEvent {
+    pub expert: Address,
+    pub attester: Address,
+    pub claim_type: Symbol,
+    pub revoked_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationPolicyEvent {
+    pub policy: AttestationPolicy,
+    pub admin: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_attested(
+    env: &Env,
+    expert: Address,
+    attester: Address,
+    claim_type: Symbol,
+    claim_hash: BytesN<32>,
+    expires_at: u64,
+) {
+    let event = AttestedEvent {
+        expert,
+        attester,
+        claim_type,
+        claim_hash,
+        expires_at,
+    };
+    env.events().publish((Symbol::new(env, "attested"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_attestation_revoked(
+    env: &Env,
+    expert: Address,
+    attester: Address,
+    claim_type: Symbol,
+    revoked_by: Address,
+) {
+    let event = AttestationRevokedEvent {
+        expert,
+        attester,
+        claim_type,
+        revoked_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "attestation_revoked"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_attestation_policy(env: &Env, policy: AttestationPolicy, admin: Address) {
+    let event = AttestationPolicyEvent { policy, admin };
+    env.events()
+        .publish((Symbol::new(env, "attestation_policy"),), event);
+}
+
+// Events for the council
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilSetEvent {
+    pub council: Council,
+    pub set_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalEvent {
+    pub id: u64,
+    pub signer: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_council_set(env: &Env, council: Council, set_by: Address) {
+    let event = CouncilSetEvent { council, set_by };
+    env.events()
+        .publish((Symbol::new(env, "council_set"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_proposal_created(env: &Env, id: u64, signer: Address) {
+    let event = ProposalEvent { id, signer };
+    env.events()
+        .publish((Symbol::new(env, "proposal_created"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_proposal_approved(env: &Env, id: u64, signer: Address) {
+    let event = ProposalEvent { id, signer };
+    env.events()
+        .publish((Symbol::new(env, "proposal_approved"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_proposal_executed(env: &Env, id: u64, signer: Address) {
+    let event = ProposalEvent { id, signer };
+    env.events()
+        .publish((Symbol::new(env, "proposal_executed"),), event);
+}
+
+// Event for URI policy changes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UriPolicyEvent {
+    pub policy: UriPolicy,
+    pub admin: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_uri_policy(env: &Env, policy: UriPolicy, admin: Address) {
+    let event = UriPolicyEvent { policy, admin };
+    env.events()
+        .publish((Symbol::new(env, "uri_policy"),), event);
+}
+
+// Events for emergency pauses
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub group: PauseGroup,
+    pub by: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_paused(env: &Env, group: PauseGroup, by: Address) {
+    let event = PauseEvent { group, by };
+    env.events().publish((Symbol::new(env, "paused"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_unpaused(env: &Env, group: PauseGroup, by: Address) {
+    let event = PauseEvent { group, by };
+    env.events().publish((Symbol::new(env, "unpaused"),), event);
+}
+
+// Events for expert handles
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandleClaimedEvent {
+    pub expert: Address,
+    pub handle: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandleReleasedEvent {
+    pub expert: Address,
+    pub handle: String,
+    pub quarantined_until: u64,
+}
+
+#[allow(deprecated)]
+pub fn emit_handle_claimed(env: &Env, expert: Address, handle: String) {
+    let event = HandleClaimedEvent { expert, handle };
+    env.events()
+        .publish((Symbol::new(env, "handle_claimed"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_handle_released(env: &Env, expert: Address, handle: String, quarantined_until: u64) {
+    let event = HandleReleasedEvent {
+        expert,
+        handle,
+        quarantined_until,
+    };
+    env.events()
+        .publish((Symbol::new(env, "handle_released"),), event);
+}
+
+// Events for identity migration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityMigratedEvent {
+    pub old_address: Address,
+    pub new_address: Address,
+    pub migrated_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequestedEvent {
+    pub old_address: Address,
+    pub new_address: Address,
+    pub executable_at: u64,
+    pub requested_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelledEvent {
+    pub old_address: Address,
+    pub cancelled_by: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_identity_migrated(
+    env: &Env,
+    old_address: Address,
+    new_address: Address,
+    migrated_by: Address,
+) {
+    let event = IdentityMigratedEvent {
+        old_address,
+        new_address,
+        migrated_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "identity_migrated"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_recovery_requested(
+    env: &Env,
+    old_address: Address,
+    new_address: Address,
+    executable_at: u64,
+    requested_by: Address,
+) {
+    let event = RecoveryRequestedEvent {
+        old_address,
+        new_address,
+        executable_at,
+        requested_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "recovery_requested"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_recovery_cancelled(env: &Env, old_address: Address, cancelled_by: Address) {
+    let event = RecoveryCancelledEvent {
+        old_address,
+        cancelled_by,
+    };
+    env.events()
+        .publish((Symbol::new(env, "recovery_cancelled"),), event);
+}
@@ -0,0 +1,98 @@
+use crate::error::RegistryError;
+use crate::events;
+use crate::storage;
+use crate::types::HandleClaim;
+use soroban_sdk::{Address, Env, String};
+
+// Expert handles
+//
+// A verified expert may hold one unique handle. Handles are lowercased before
+// they are stored or looked up, so lookups ignore case. A banned expert's
+// handle is quarantined for a while before anyone else may claim it.
+
+/// Shortest allowed handle
+pub const MIN_HANDLE_LEN: u32 = 3;
+
+/// Longest allowed handle, the same bound as a Symbol
+pub const MAX_HANDLE_LEN: u32 = 32;
+
+/// Seconds a banned expert's handle stays reserved (30 days)
+pub const QUARANTINE_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+/// Lowercase the handle and check it only uses a-z, 0-9 and _
+pub fn normalize(env: &Env, handle: &String) -> Result<String, RegistryError> {
+    let len = handle.len();
+    if !(MIN_HANDLE_LEN..=MAX_HANDLE_LEN).contains(&len) {
+        return Err(RegistryError::InvalidHandle);
+    }
+
+    let mut buf = [0u8; MAX_HANDLE_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    handle.copy_into_slice(bytes);
+    for b in bytes.iter_mut() {
+        b.make_ascii_lowercase();
+        if !(b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'_') {
+            return Err(RegistryError::InvalidHandle);
+        }
+    }
+
+    Ok(String::from_bytes(env, bytes))
+}
+
+/// Give a normalized handle to the expert, freeing the one they held before.
+/// The banned owner of a quarantined handle may take it back.
+pub fn claim(env: &Env, expert: &Address, handle: &String) -> Result<(), RegistryError> {
+    if let Some(claim) = storage::get_handle_claim(env, handle) {
+        let held =
+            claim.quarantined_until == 0 || env.ledger().timestamp() < claim.quarantined_until;
+        if claim.owner != *expert && held {
+            return Err(RegistryError::HandleTaken);
+        }
+    }
+
+    let mut record = storage::get_expert_record(env, expert);
+    if let Some(old) = record.handle.take() {
+        storage::remove_handle_claim(env, &old);
+    }
+    storage::set_handle_claim(
+        env,
+        handle,
+        &HandleClaim {
+            owner: expert.clone(),
+            quarantined_until: 0,
+        },
+    );
+    record.handle = Some(handle.clone());
+    storage::save_expert_record(env, expert, &record);
+
+    Ok(())
+}
+
+/// Take the expert's handle, if any, and hold it in quarantine
+pub fn quarantine(env: &Env, expert: &Address) {
+    let mut record = storage::get_expert_record(env, expert);
+    let Some(handle) = record.handle.take() else {
+        return;
+    };
+
+    let quarantined_until = env.ledger().timestamp().saturating_add(QUARANTINE_PERIOD);
+    storage::set_handle_claim(
+        env,
+        &handle,
+        &HandleClaim {
+            owner: expert.clone(),
+            quarantined_until,
+        },
+    );
+    storage::save_expert_record(env, expert, &record);
+    events::emit_handle_released(env, expert.clone(), handle, quarantined_until);
+}
+
+/// Owner of an actively claimed handle
+pub fn resolve(env: &Env, handle: &String) -> Result<Address, RegistryError> {
+    let handle = normalize(env, handle)?;
+    match storage::get_handle_claim(env, &handle) {
+        Some(claim) if claim.quarantined_until == 0 => Ok(claim.owner),
+        _ => Err(RegistryError::HandleNotFound),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::storage;
+use crate::types::{ExpertStatus, StatusChange};
+use soroban_sdk::{Address, Env};
+
+// Per-expert status history
+//
+// Expert records only hold the latest status, so every transition is also
+// appended to a bounded log that outlives event retention on RPC nodes.
+
+/// Maximum number of entries kept per expert; the oldest are dropped first
+pub const MAX_HISTORY: u32 = 50;
+
+/// Append a status transition to the expert's history
+pub fn record(
+    env: &Env,
+    expert: &Address,
+    old_status: ExpertStatus,
+    new_status: ExpertStatus,
+    actor: &Address,
+    reason_code: u32,
+) {
+    let mut history = storage::get_status_history(env, expert);
+    if history.len() >= MAX_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(StatusChange {
+        timestamp: env.ledger().timestamp(),
+        old_status,
+        new_status,
+        actor: actor.clone(),
+        reason_code,
+    });
+    storage::set_status_history(env, expert, &history);
+}
+
+/// Status the expert held right before their latest ban.
+/// Unverified when the ban is not on record (e.g. it predates the history log).
+pub fn status_before_ban(env: &Env, expert: &Address) -> ExpertStatus {
+    storage::get_status_history(env, expert)
+        .iter()
+        .rev()
+        .find(|change| change.new_status == ExpertStatus::Banned)
+        .map(|change| change.old_status)
+        .unwrap_or(ExpertStatus::Unverified)
+}
@@ -0,0 +1,98 @@
+use crate::error::IdentityError;
+use crate::skills;
+use crate::storage;
+use crate::types::{ApplicationStatus, ExpertRecord, ExpertStatus, HandleClaim};
+use soroban_sdk::{Address, Env, Vec};
+
+// Identity migration
+//
+// Moves everything the registry keeps for an expert to a new address: the
+// record, the directory slot, skill listings, the verification token, the
+// handle, attestations, a pending appeal and the status history. The old
+// address keeps a Migrated record and a pointer to the new one.
+
+/// Seconds an admin recovery waits before it can run (7 days), leaving the
+/// old key time to cancel it
+pub const RECOVERY_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+
+/// Fail unless `old` has a record to move and `new` has never been used,
+/// including by attestations or a pending application filed for it
+pub fn ensure_migratable(env: &Env, old: &Address, new: &Address) -> Result<(), IdentityError> {
+    if !storage::has_expert_record(env, old) {
+        return Err(IdentityError::ExpertNotFound);
+    }
+    if storage::get_expert_record(env, old).status == ExpertStatus::Migrated {
+        return Err(IdentityError::AlreadyMigrated);
+    }
+    if old == new || storage::has_expert_record(env, new) || storage::is_indexed(env, new) {
+        return Err(IdentityError::IdentityInUse);
+    }
+    let pending = storage::get_application(env, new)
+        .is_some_and(|application| application.status == ApplicationStatus::Pending);
+    if pending || !storage::get_attestations(env, new).is_empty() {
+        return Err(IdentityError::IdentityInUse);
+    }
+    Ok(())
+}
+
+/// Move the expert's state from `old` to `new`. Callers must have run
+/// `ensure_migratable` and settled any pending application.
+pub fn migrate(env: &Env, old: &Address, new: &Address) {
+    let record = storage::get_expert_record(env, old);
+
+    storage::replace_in_index(env, old, new);
+
+    // Only stored-Verified experts are listed under their tags
+    skills::unindex_expert(env, old, &record.skills);
+    if record.status == ExpertStatus::Verified {
+        skills::index_expert(env, new, &record.skills);
+    }
+
+    // The token keeps its id
+    if let Some(token_id) = storage::get_token_of(env, old) {
+        storage::remove_token_owner(env, token_id, old);
+        storage::set_token_owner(env, token_id, new);
+    }
+
+    if let Some(handle) = &record.handle {
+        storage::set_handle_claim(
+            env,
+            handle,
+            &HandleClaim {
+                owner: new.clone(),
+                quarantined_until: 0,
+            },
+        );
+    }
+
+    let attestations = storage::get_attestations(env, old);
+    if !attestations.is_empty() {
+        storage::set_attestations(env, new, &attestations);
+        storage::set_attestations(env, old, &Vec::new(env));
+    }
+
+    if let Some(appeal) = storage::get_appeal(env, old) {
+        storage::set_appeal(env, new, &appeal);
+        storage::remove_appeal(env, old);
+    }
+
+    storage::set_status_history(env, new, &storage::get_status_history(env, old));
+
+    // The old record stays behind as a forwarding pointer
+    let forwarded = ExpertRecord {
+        status: ExpertStatus::Migrated,
+        updated_at: env.ledger().timestamp(),
+        data_uri: record.data_uri.clone(),
+        data_hash: record.data_hash.clone(),
+        skills: Vec::new(env),
+        suspended_until: 0,
+        bond: 0,
+        bonded_at: 0,
+        verified_at: 0,
+        verified_until: 0,
+        handle: None,
+    };
+    storage::save_expert_record(env, new, &record);
+    storage::save_expert_record(env, old, &forwarded);
+    storage::set_migrated_to(env, old, new);
+}
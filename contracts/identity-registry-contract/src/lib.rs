@@ -1,16 +1,30 @@
 #![no_std]
 
+mod attestations;
+mod bond;
 mod contract;
+mod council;
 mod error;
 mod events;
+mod handles;
+mod history;
+mod identity;
+mod migration;
+mod sbt;
+mod skills;
 mod storage;
 #[cfg(test)]
 mod test;
 mod types;
+mod uri;
 
-use crate::error::RegistryError;
-use crate::types::ExpertStatus;
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use crate::error::{IdentityError, RegistryError};
+use crate::types::{
+    Appeal, Application, ApplicationPage, Attestation, AttestationPolicy, BatchResult, BondConfig,
+    Council, CouncilAction, ExpertPage, ExpertStatus, PauseGroup, Proposal, RecoveryRequest, Role,
+    StatusHistoryPage, UriPolicy,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contract]
 pub struct IdentityRegistryContract;
@@ -22,25 +36,367 @@ impl IdentityRegistryContract {
         contract::initialize_registry(&env, &admin)
     }
 
-    /// Batch Add an expert to the whitelist (Admin only)
-    pub fn batch_add_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
-        contract::batch_add_experts(env, experts)
+    /// Upgrade the contract code to an uploaded wasm (Admin only)
+    /// Needs a council proposal once a council is installed
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), RegistryError> {
+        contract::upgrade(&env, new_wasm_hash)
     }
 
-    /// Batch ban experts and revoke their verification status (Admin only)
-    pub fn batch_ban_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
-        contract::batch_ban_experts(env, experts)
+    /// Migrate storage written by an older contract version, one page of
+    /// directory slots at a time (Admin only)
+    /// Returns the slot to pass as `start` next, or None once the last page is
+    /// done and the version has been bumped
+    pub fn migrate(env: Env, start: u64, limit: u32) -> Result<Option<u64>, RegistryError> {
+        contract::migrate(&env, start, limit)
     }
 
-    /// Add an expert to the whitelist (Admin only)
-    /// Also saves a profile data_uri reference (e.g., ipfs://...)
-    pub fn add_expert(env: Env, expert: Address, data_uri: String) -> Result<(), RegistryError> {
-        contract::verify_expert(&env, &expert, data_uri)
+    /// Get the storage layout version
+    pub fn get_version(env: Env) -> u32 {
+        contract::get_version(&env)
     }
 
-    /// Ban an expert and revoke their verification status (Admin only)
-    pub fn ban_expert(env: Env, expert: Address) -> Result<(), RegistryError> {
-        contract::ban_expert(&env, &expert)
+    /// Propose a new admin (Admin only)
+    /// The proposed address must call `accept_admin` to take over
+    /// Needs a council proposal once a council is installed
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), RegistryError> {
+        contract::transfer_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin transfer (Proposed admin only)
+    pub fn accept_admin(env: Env) -> Result<(), RegistryError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Grant a role to an account (SuperAdmin only)
+    /// Needs a council proposal once a council is installed
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), RegistryError> {
+        contract::grant_role(&env, &caller, role, &account)
+    }
+
+    /// Revoke a role from an account (SuperAdmin only)
+    /// Needs a council proposal once a council is installed
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), RegistryError> {
+        contract::revoke_role(&env, &caller, role, &account)
+    }
+
+    /// Check if an account holds a role (the admin holds every role)
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        contract::has_role(&env, role, &account)
+    }
+
+    /// Batch Add an expert to the whitelist (Verifier only)
+    pub fn batch_add_experts(
+        env: Env,
+        caller: Address,
+        experts: Vec<Address>,
+    ) -> Result<(), RegistryError> {
+        contract::batch_add_experts(env, caller, experts)
+    }
+
+    /// Batch add experts, each with their own profile URI (Verifier only)
+    /// Returns a result per expert; failed items are skipped instead of reverting the batch
+    pub fn batch_add_experts_with_uris(
+        env: Env,
+        caller: Address,
+        experts: Vec<(Address, String)>,
+    ) -> Result<Vec<BatchResult>, RegistryError> {
+        contract::batch_add_experts_with_uris(&env, &caller, experts)
+    }
+
+    /// Batch ban experts and revoke their verification status (Moderator only)
+    /// Needs a council proposal once a council is installed
+    pub fn batch_ban_experts(
+        env: Env,
+        caller: Address,
+        experts: Vec<Address>,
+    ) -> Result<(), RegistryError> {
+        contract::batch_ban_experts(env, caller, experts)
+    }
+
+    /// Batch ban experts, reporting a result per expert (Moderator only)
+    /// Failed items are skipped instead of reverting the batch.
+    /// Needs a council proposal once a council is installed
+    pub fn batch_ban_experts_with_results(
+        env: Env,
+        caller: Address,
+        experts: Vec<Address>,
+    ) -> Result<Vec<BatchResult>, RegistryError> {
+        contract::batch_ban_experts_with_results(&env, &caller, experts)
+    }
+
+    /// Add an expert to the whitelist (Verifier only)
+    /// Also saves a profile data_uri reference (e.g., ipfs://...) and the hash of its content
+    pub fn add_expert(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        data_uri: String,
+        data_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        contract::verify_expert(&env, &caller, &expert, data_uri, data_hash)
+    }
+
+    /// Ban an expert and revoke their verification status (Moderator only)
+    /// Needs a council proposal once a council is installed
+    pub fn ban_expert(env: Env, caller: Address, expert: Address) -> Result<(), RegistryError> {
+        contract::ban_expert(&env, &caller, &expert)
+    }
+
+    /// Suspend a verified expert for `duration` seconds (Moderator only)
+    /// The expert counts as verified again once the suspension runs out
+    /// Needs a council proposal once a council is installed
+    pub fn suspend_expert(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        duration: u64,
+        reason_code: u32,
+    ) -> Result<(), RegistryError> {
+        contract::suspend_expert(&env, &caller, &expert, duration, reason_code)
+    }
+
+    /// Appeal the caller's current ban with a link to supporting evidence (once per ban)
+    pub fn file_appeal(
+        env: Env,
+        expert: Address,
+        evidence_uri: String,
+    ) -> Result<(), RegistryError> {
+        contract::file_appeal(&env, &expert, evidence_uri)
+    }
+
+    /// Resolve a pending appeal, reinstating the expert if accepted (Moderator only)
+    pub fn resolve_appeal(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        accept: bool,
+    ) -> Result<(), RegistryError> {
+        contract::resolve_appeal(&env, &caller, &expert, accept)
+    }
+
+    /// Get the latest appeal filed by an expert
+    pub fn get_appeal(env: Env, expert: Address) -> Result<Appeal, RegistryError> {
+        contract::get_appeal(&env, &expert)
+    }
+
+    /// Ask to be verified; the application waits in the review queue
+    pub fn apply_for_verification(
+        env: Env,
+        expert: Address,
+        data_uri: String,
+        data_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        contract::apply_for_verification(&env, &expert, data_uri, data_hash)
+    }
+
+    /// Approve a pending application, verifying the applicant (Verifier only)
+    pub fn approve_application(
+        env: Env,
+        caller: Address,
+        expert: Address,
+    ) -> Result<(), RegistryError> {
+        contract::approve_application(&env, &caller, &expert)
+    }
+
+    /// Reject a pending application with a reason code (Verifier only)
+    pub fn reject_application(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        reason: u32,
+    ) -> Result<(), RegistryError> {
+        contract::reject_application(&env, &caller, &expert, reason)
+    }
+
+    /// Get the latest application filed by an expert
+    pub fn get_application(env: Env, expert: Address) -> Result<Application, RegistryError> {
+        contract::get_application(&env, &expert)
+    }
+
+    /// List a page of the applications awaiting review
+    pub fn list_applications(env: Env, start: u64, limit: u32) -> ApplicationPage {
+        contract::list_applications(&env, start, limit)
+    }
+
+    /// Configure the verification bond; an amount of zero makes it optional (SuperAdmin only)
+    pub fn set_bond_config(
+        env: Env,
+        caller: Address,
+        config: BondConfig,
+    ) -> Result<(), RegistryError> {
+        contract::set_bond_config(&env, &caller, config)
+    }
+
+    /// Get the verification bond configuration
+    pub fn get_bond_config(env: Env) -> Result<BondConfig, RegistryError> {
+        contract::get_bond_config(&env)
+    }
+
+    /// Lock tokens into the caller's verification bond
+    pub fn deposit_bond(env: Env, expert: Address, amount: i128) -> Result<(), RegistryError> {
+        contract::deposit_bond(&env, &expert, amount)
+    }
+
+    /// Withdraw the whole bond after the cooldown; not allowed while suspended
+    pub fn withdraw_bond(env: Env, expert: Address) -> Result<i128, RegistryError> {
+        contract::withdraw_bond(&env, &expert)
+    }
+
+    /// Set how long verifications stay valid in seconds, 0 for forever (SuperAdmin only)
+    /// Verifications made without a period lapse this long after they were last verified or renewed.
+    /// Needs a council proposal once a council is installed
+    pub fn set_verification_period(
+        env: Env,
+        caller: Address,
+        period: u64,
+    ) -> Result<(), RegistryError> {
+        contract::set_verification_period(&env, &caller, period)
+    }
+
+    /// Get how long verifications stay valid in seconds (0 = forever)
+    pub fn get_verification_period(env: Env) -> u64 {
+        contract::get_verification_period(&env)
+    }
+
+    /// Restart an expert's verification period from now (Verifier only)
+    /// Returns the new verified_until (0 if verifications do not expire)
+    pub fn renew_verification(
+        env: Env,
+        caller: Address,
+        expert: Address,
+    ) -> Result<u64, RegistryError> {
+        contract::renew_verification(&env, &caller, &expert)
+    }
+
+    /// List a page of verified experts whose verification lapses before `before_ts`
+    pub fn list_expiring(env: Env, before_ts: u64, start: u64, limit: u32) -> ExpertPage {
+        contract::list_expiring(&env, before_ts, start, limit)
+    }
+
+    /// Attest to a claim about an expert by its hash; `expires_at` of 0 never expires (Attester only)
+    pub fn attest(
+        env: Env,
+        attester: Address,
+        expert: Address,
+        claim_type: Symbol,
+        claim_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<(), RegistryError> {
+        contract::attest(&env, &attester, &expert, claim_type, claim_hash, expires_at)
+    }
+
+    /// Revoke an attestation (the attester who made it, or a Moderator)
+    pub fn revoke_attestation(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        attester: Address,
+        claim_type: Symbol,
+    ) -> Result<(), RegistryError> {
+        contract::revoke_attestation(&env, &caller, &expert, &attester, claim_type)
+    }
+
+    /// Get every attestation recorded for an expert
+    pub fn get_attestations(env: Env, expert: Address) -> Vec<Attestation> {
+        contract::get_attestations(&env, &expert)
+    }
+
+    /// Set the attestations required before an expert can be verified (SuperAdmin only)
+    pub fn set_attestation_policy(
+        env: Env,
+        caller: Address,
+        policy: AttestationPolicy,
+    ) -> Result<(), RegistryError> {
+        contract::set_attestation_policy(&env, &caller, policy)
+    }
+
+    /// Get the attestations required before an expert can be verified
+    pub fn get_attestation_policy(env: Env) -> Option<AttestationPolicy> {
+        contract::get_attestation_policy(&env)
+    }
+
+    /// Set the length limit and allowed schemes for every stored URI (SuperAdmin only)
+    pub fn set_uri_policy(
+        env: Env,
+        caller: Address,
+        policy: UriPolicy,
+    ) -> Result<(), RegistryError> {
+        contract::set_uri_policy(&env, &caller, policy)
+    }
+
+    /// Get the URI policy in force; 64 bytes of any scheme until one is set
+    pub fn get_uri_policy(env: Env) -> UriPolicy {
+        contract::get_uri_policy(&env)
+    }
+
+    /// Pause a group of entrypoints in an emergency (Guardian only)
+    /// Bans, appeals and bond withdrawals keep working while paused
+    pub fn pause(env: Env, caller: Address, group: PauseGroup) -> Result<(), RegistryError> {
+        contract::pause(&env, &caller, group)
+    }
+
+    /// Lift the pause on a group of entrypoints (Guardian only)
+    pub fn unpause(env: Env, caller: Address, group: PauseGroup) -> Result<(), RegistryError> {
+        contract::unpause(&env, &caller, group)
+    }
+
+    /// Check whether a group of entrypoints is paused
+    pub fn is_paused(env: Env, group: PauseGroup) -> bool {
+        contract::is_paused(&env, group)
+    }
+
+    /// Install the first council (SuperAdmin only)
+    /// Once installed, bans, suspensions, role changes, admin transfers, key
+    /// recoveries, verification period changes, upgrades and council changes need a proposal
+    pub fn set_council(env: Env, caller: Address, council: Council) -> Result<(), RegistryError> {
+        contract::set_council(&env, &caller, council)
+    }
+
+    /// Get the current council, if one is installed
+    pub fn get_council(env: Env) -> Option<Council> {
+        contract::get_council(&env)
+    }
+
+    /// Open a proposal for a sensitive action; counts as the proposer's approval (council only)
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: CouncilAction,
+    ) -> Result<u64, RegistryError> {
+        contract::propose(&env, &proposer, action)
+    }
+
+    /// Approve an open proposal (council only)
+    pub fn approve_proposal(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), RegistryError> {
+        contract::approve_proposal(&env, &signer, proposal_id)
+    }
+
+    /// Run a proposal once it has enough approvals and before it expires (council only)
+    pub fn execute_proposal(
+        env: Env,
+        caller: Address,
+        proposal_id: u64,
+    ) -> Result<(), RegistryError> {
+        contract::execute_proposal(&env, &caller, proposal_id)
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, RegistryError> {
+        contract::get_proposal(&env, proposal_id)
     }
 
     /// Get the total number of verified experts ever added to the directory
@@ -49,23 +405,180 @@ impl IdentityRegistryContract {
     }
 
     /// Get the expert address at the given index in the directory
-    pub fn get_expert_by_index(env: Env, index: u64) -> Address {
+    pub fn get_expert_by_index(env: Env, index: u64) -> Result<Address, RegistryError> {
         contract::get_expert_by_index(&env, index)
     }
 
+    /// List a page of the directory, optionally filtered by status.
+    /// Pass the returned `next_cursor` as `start` to fetch the following page.
+    pub fn list_experts(
+        env: Env,
+        start: u64,
+        limit: u32,
+        status_filter: Option<ExpertStatus>,
+    ) -> ExpertPage {
+        contract::list_experts(&env, start, limit, status_filter)
+    }
+
     /// Get the current status of an expert
     pub fn get_status(env: Env, expert: Address) -> ExpertStatus {
         contract::get_expert_status(&env, &expert)
     }
 
+    /// List a page of an expert's status changes, oldest first.
+    /// Only the most recent 50 changes are kept.
+    pub fn get_status_history(
+        env: Env,
+        expert: Address,
+        start: u64,
+        limit: u32,
+    ) -> StatusHistoryPage {
+        contract::get_status_history(&env, &expert, start, limit)
+    }
+
     /// Check if an expert is verified
     /// Returns true only if the expert's status is Verified
     pub fn is_verified(env: Env, expert: Address) -> bool {
         contract::is_verified(&env, &expert)
     }
 
-    /// Allow a verified expert to update their own profile URI
-    pub fn update_profile(env: Env, expert: Address, new_uri: String) -> Result<(), RegistryError> {
-        contract::update_profile(&env, &expert, new_uri)
+    /// Allow a verified expert to update their own profile URI and content hash
+    pub fn update_profile(
+        env: Env,
+        expert: Address,
+        new_uri: String,
+        new_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        contract::update_profile(&env, &expert, new_uri, new_hash)
+    }
+
+    /// Check a fetched profile document's hash against the one the expert registered
+    /// Experts added in a batch have no hash yet, so nothing matches until they set one.
+    pub fn verify_profile_hash(env: Env, expert: Address, hash: BytesN<32>) -> bool {
+        contract::verify_profile_hash(&env, &expert, &hash)
+    }
+
+    /// Claim a unique handle of 3-32 letters, digits or underscores (the verified expert only)
+    /// Handles are stored lowercased; claiming again frees the previous one.
+    /// A ban frees the handle after a 30 day quarantine.
+    pub fn claim_handle(env: Env, expert: Address, handle: String) -> Result<(), RegistryError> {
+        contract::claim_handle(&env, &expert, handle)
+    }
+
+    /// Get the expert holding a handle, ignoring case
+    pub fn resolve_handle(env: Env, handle: String) -> Result<Address, RegistryError> {
+        contract::resolve_handle(&env, &handle)
+    }
+
+    /// Get the handle an expert holds, if any
+    pub fn get_handle(env: Env, expert: Address) -> Option<String> {
+        contract::get_handle(&env, &expert)
+    }
+
+    /// Move an expert's verification, directory slot, token, handle and history
+    /// to a new address (both the old and the new key must sign)
+    /// The old address then reports Migrated and forwards to the new one.
+    pub fn migrate_identity(env: Env, old: Address, new: Address) -> Result<(), IdentityError> {
+        contract::migrate_identity(&env, &old, &new)
+    }
+
+    /// Start recovering an expert whose key was lost (SuperAdmin only)
+    /// The migration can be executed after a 7 day timelock, during which the old key may cancel it.
+    /// Once a council is installed, a `Recover` proposal must approve the same new address first.
+    /// Returns the ledger timestamp the recovery can be executed from
+    pub fn request_recovery(
+        env: Env,
+        caller: Address,
+        old: Address,
+        new: Address,
+    ) -> Result<u64, IdentityError> {
+        contract::request_recovery(&env, &caller, &old, &new)
+    }
+
+    /// Cancel a pending recovery (the expert's old key or a SuperAdmin)
+    pub fn cancel_recovery(env: Env, caller: Address, old: Address) -> Result<(), IdentityError> {
+        contract::cancel_recovery(&env, &caller, &old)
+    }
+
+    /// Migrate an expert to the address in their recovery request once the timelock has passed (SuperAdmin only)
+    /// Needs the council's `Recover` approval once a council is installed
+    pub fn execute_recovery(env: Env, caller: Address, old: Address) -> Result<(), IdentityError> {
+        contract::execute_recovery(&env, &caller, &old)
+    }
+
+    /// Get the pending recovery for an expert, if any
+    pub fn get_recovery(env: Env, old: Address) -> Option<RecoveryRequest> {
+        contract::get_recovery(&env, &old)
+    }
+
+    /// Get the address a migrated expert moved to
+    pub fn get_migrated_to(env: Env, old: Address) -> Option<Address> {
+        contract::get_migrated_to(&env, &old)
+    }
+
+    /// Replace an expert's skill tags (the expert themselves or a Verifier)
+    pub fn set_skills(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        skills: Vec<Symbol>,
+    ) -> Result<(), RegistryError> {
+        contract::set_skills(&env, &caller, &expert, skills)
+    }
+
+    /// List a page of the verified experts tagged with a skill
+    pub fn get_experts_by_skill(env: Env, tag: Symbol, start: u64, limit: u32) -> ExpertPage {
+        contract::get_experts_by_skill(&env, &tag, start, limit)
+    }
+
+    /// Get the number of verification tokens held by an address (0 or 1)
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        contract::balance(&env, &owner)
+    }
+
+    /// Get the owner of a verification token
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, RegistryError> {
+        contract::owner_of(&env, token_id)
+    }
+
+    /// Get the verification token held by an expert
+    pub fn token_of(env: Env, owner: Address) -> Result<u64, RegistryError> {
+        contract::token_of(&env, &owner)
+    }
+
+    /// Get the metadata URI of a verification token (points at the expert's profile)
+    pub fn token_uri(env: Env, token_id: u64) -> Result<String, RegistryError> {
+        contract::token_uri(&env, token_id)
+    }
+
+    /// Verification tokens are soulbound: always fails
+    pub fn transfer(
+        _env: Env,
+        _from: Address,
+        _to: Address,
+        _token_id: u64,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::SoulboundNonTransferable)
+    }
+
+    /// Verification tokens are soulbound: always fails
+    pub fn transfer_from(
+        _env: Env,
+        _spender: Address,
+        _from: Address,
+        _to: Address,
+        _token_id: u64,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::SoulboundNonTransferable)
+    }
+
+    /// Verification tokens are soulbound: always fails
+    pub fn approve(
+        _env: Env,
+        _owner: Address,
+        _approved: Address,
+        _token_id: u64,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::SoulboundNonTransferable)
     }
 }
@@ -0,0 +1,108 @@
+use crate::sbt;
+use crate::storage::{self, DataKey};
+use crate::types::{ExpertRecord, ExpertStatus};
+use soroban_sdk::{contracttype, BytesN, Env, String, Vec};
+
+/// Storage layout version written by this build
+pub const CONTRACT_VERSION: u32 = 2;
+
+/// Version assumed for registries deployed before the version key existed
+pub const LEGACY_VERSION: u32 = 1;
+
+/// ExpertRecord as written by version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpertRecordV1 {
+    pub status: ExpertStatus,
+    pub updated_at: u64,
+    pub data_uri: String,
+}
+
+impl ExpertRecordV1 {
+    /// Number of fields in the version 1 layout, used to tell it apart when read back
+    pub const FIELDS: u32 = 3;
+
+    /// Convert to the current layout; version 1 had no skill tags, suspensions,
+    /// bonds, expiry, content hash or handles. A version 1 record was last written
+    /// when its status changed, so that stands in for the verification time and
+    /// migrated verifications lapse a period after it once a period is set. The
+    /// hash stays all-zero until the expert next updates their profile.
+    pub fn into_record(self, env: &Env) -> ExpertRecord {
+        ExpertRecord {
+            status: self.status,
+            updated_at: self.updated_at,
+            data_uri: self.data_uri,
+            data_hash: BytesN::from_array(env, &[0u8; 32]),
+            skills: Vec::new(env),
+            suspended_until: 0,
+            bond: 0,
+            bonded_at: 0,
+            verified_at: self.updated_at,
+            verified_until: 0,
+            handle: None,
+        }
+    }
+}
+
+/// Most directory slots a single `migrate` call walks. Each slot writes up to
+/// five ledger entries, which keeps a page within one transaction's limits.
+pub const MIGRATION_PAGE_SIZE: u32 = 10;
+
+/// Rewrite the expert records in one page of the directory index from the
+/// version 1 layout, record their slot and mint the verification token that
+/// version 1 did not issue. Duplicate slots are filled from the end of the
+/// directory, so the count shrinks to one slot per expert.
+///
+/// Only a page starting at or before the stored cursor moves it forward, so
+/// no slot is skipped. Returns the slot to continue from, or None once the
+/// whole directory has been walked, together with the records rewritten so far.
+pub fn migrate_from_v1(env: &Env, start: u64, limit: u32) -> (Option<u64>, u32) {
+    let mut total = storage::get_total_experts(env);
+    let (cursor, mut migrated) = storage::get_migration_progress(env);
+    let end = start.saturating_add(limit.clamp(1, MIGRATION_PAGE_SIZE) as u64);
+
+    let mut index = start;
+    while index < end.min(total) {
+        let Some(expert) = storage::get_expert_by_index(env, index) else {
+            index += 1;
+            continue;
+        };
+        // Version 1 re-indexed experts verified again after a ban
+        // and kept no marker of who already had a slot
+        if storage::is_indexed(env, &expert) {
+            if storage::get_index_position(env, &expert) == Some(index) {
+                // Walked by an earlier page
+                index += 1;
+            } else {
+                // A duplicate: the slot now holds the last one, so look at it again
+                total = storage::remove_index_slot(env, index);
+            }
+            continue;
+        }
+        storage::mark_indexed(env, &expert);
+        storage::set_index_position(env, &expert, index);
+
+        let key = DataKey::Expert(expert.clone());
+        let old: Option<ExpertRecordV1> = env.storage().persistent().get(&key);
+        if let Some(old) = old {
+            if old.status == ExpertStatus::Verified {
+                sbt::mint(env, &expert);
+            }
+            storage::save_expert_record(env, &expert, &old.into_record(env));
+            migrated += 1;
+        }
+        index += 1;
+    }
+
+    let cursor = if start <= cursor {
+        cursor.max(end.min(total))
+    } else {
+        cursor
+    };
+    if cursor < total {
+        storage::set_migration_progress(env, cursor, migrated);
+        return (Some(cursor), migrated);
+    }
+    storage::remove_migration_progress(env);
+    (None, migrated)
+}
@@ -0,0 +1,54 @@
+use crate::error::RegistryError;
+use crate::events;
+use crate::storage;
+use soroban_sdk::{Address, Env, String};
+
+// Soulbound verification token
+//
+// Every verified expert holds exactly one non-transferable token. It is minted
+// when the expert is verified and burned when they are banned, so holding a
+// token is equivalent to having been verified and not banned since.
+
+/// Mint a verification token to the expert if they do not already hold one
+pub fn mint(env: &Env, expert: &Address) {
+    if storage::get_token_of(env, expert).is_some() {
+        return;
+    }
+
+    let token_id = storage::get_next_token_id(env);
+    storage::set_token_owner(env, token_id, expert);
+    events::emit_sbt_minted(env, token_id, expert.clone());
+}
+
+/// Burn the expert's verification token, if any
+pub fn burn(env: &Env, expert: &Address) {
+    if let Some(token_id) = storage::get_token_of(env, expert) {
+        storage::remove_token_owner(env, token_id, expert);
+        events::emit_sbt_burned(env, token_id, expert.clone());
+    }
+}
+
+/// Number of verification tokens held (0 or 1)
+pub fn balance(env: &Env, owner: &Address) -> u32 {
+    if storage::get_token_of(env, owner).is_some() {
+        1
+    } else {
+        0
+    }
+}
+
+/// Owner of a live token
+pub fn owner_of(env: &Env, token_id: u64) -> Result<Address, RegistryError> {
+    storage::get_token_owner(env, token_id).ok_or(RegistryError::TokenNotFound)
+}
+
+/// Token held by an expert
+pub fn token_of(env: &Env, owner: &Address) -> Result<u64, RegistryError> {
+    storage::get_token_of(env, owner).ok_or(RegistryError::TokenNotFound)
+}
+
+/// Token metadata is the owner's profile data_uri
+pub fn token_uri(env: &Env, token_id: u64) -> Result<String, RegistryError> {
+    let owner = owner_of(env, token_id)?;
+    Ok(storage::get_expert_record(env, &owner).data_uri)
+}
@@ -0,0 +1,41 @@
+use crate::error::RegistryError;
+use crate::storage;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+// Skill tags
+//
+// Each verified expert is listed under every tag on their record. Banned
+// experts keep their tags on the record but are dropped from the tag
+// indexes until they are verified again.
+
+/// Maximum number of skill tags on a single expert
+pub const MAX_SKILLS: u32 = 5;
+
+/// Reject oversized or repeated tag lists
+pub fn validate(skills: &Vec<Symbol>) -> Result<(), RegistryError> {
+    if skills.len() > MAX_SKILLS {
+        return Err(RegistryError::TooManySkills);
+    }
+
+    for (i, tag) in skills.iter().enumerate() {
+        if skills.iter().skip(i + 1).any(|other| other == tag) {
+            return Err(RegistryError::DuplicateSkill);
+        }
+    }
+
+    Ok(())
+}
+
+/// List the expert under each of the given tags
+pub fn index_expert(env: &Env, expert: &Address, skills: &Vec<Symbol>) {
+    for tag in skills.iter() {
+        storage::add_expert_to_skill(env, &tag, expert);
+    }
+}
+
+/// Remove the expert from each of the given tags
+pub fn unindex_expert(env: &Env, expert: &Address, skills: &Vec<Symbol>) {
+    for tag in skills.iter() {
+        storage::remove_expert_from_skill(env, &tag, expert);
+    }
+}
@@ -1,14 +1,53 @@
-use crate::types::{ExpertRecord, ExpertStatus};
-use soroban_sdk::{contracttype, Address, Env, String};
+use crate::migration::{ExpertRecordV1, LEGACY_VERSION};
+use crate::types::{
+    Appeal, Application, Attestation, AttestationPolicy, BondConfig, Council, ExpertRecord,
+    ExpertStatus, HandleClaim, PauseGroup, Proposal, RecoveryRequest, Role, StatusChange,
+    UriPolicy,
+};
+use soroban_sdk::{
+    contracttype, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
 
 // 1. Data Keys
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
+    Role(Role, Address),
     Expert(Address),
     VerifiedExpertIndex(u64),
+    Indexed(Address),
     TotalVerifiedCount,
+    ContractVersion,
+    TokenCounter,
+    TokenOwner(u64),
+    TokenOf(Address),
+    SkillIndex(Symbol, u64),
+    SkillCount(Symbol),
+    SkillPosition(Symbol, Address),
+    Appeal(Address),
+    Application(Address),
+    PendingApplicationIndex(u64),
+    PendingApplicationCount,
+    PendingApplicationPosition(Address),
+    BondConfig,
+    BondedTotal,
+    VerificationPeriod,
+    Attestations(Address),
+    AttestationPolicy,
+    Council,
+    ProposalCounter,
+    Proposal(u64),
+    StatusHistory(Address),
+    UriPolicy,
+    Paused(PauseGroup),
+    Handle(String),
+    IndexPosition(Address),
+    MigratedTo(Address),
+    Recovery(Address),
+    RecoveryApproval(Address),
+    MigrationProgress,
 }
 
 // Constants for TTL (Time To Live)
@@ -40,20 +79,125 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Admin)
 }
 
+/// Set the proposed admin awaiting acceptance
+pub fn set_pending_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::PendingAdmin, admin);
+}
+
+/// Get the proposed admin awaiting acceptance
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+/// Clear the proposed admin once accepted
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+// ... [Version Helpers] ...
+
+/// Set the storage layout version
+pub fn set_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ContractVersion, &version);
+}
+
+/// Get the storage layout version (deployments that predate the key are version 1)
+pub fn get_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractVersion)
+        .unwrap_or(LEGACY_VERSION)
+}
+
+/// Get how far a paged migration has got: the next directory slot and the records rewritten so far
+pub fn get_migration_progress(env: &Env) -> (u64, u32) {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationProgress)
+        .unwrap_or((0, 0))
+}
+
+/// Record how far a paged migration has got
+pub fn set_migration_progress(env: &Env, cursor: u64, records: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationProgress, &(cursor, records));
+}
+
+/// Clear the migration progress once the last page is done
+pub fn remove_migration_progress(env: &Env) {
+    env.storage().instance().remove(&DataKey::MigrationProgress);
+}
+
+// ... [Role Helpers] ...
+
+/// Grant a role to an account
+pub fn grant_role(env: &Env, role: Role, account: &Address) {
+    let key = DataKey::Role(role, account.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Revoke a role from an account
+pub fn revoke_role(env: &Env, role: Role, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Role(role, account.clone()));
+}
+
+/// Check if an account holds a role, extending TTL if it does
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    let key = DataKey::Role(role, account.clone());
+
+    if env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+        return true;
+    }
+
+    false
+}
+
 // ... [Expert Helpers] ...
 
 /// Set the expert record with status, data_uri and timestamp
-pub fn set_expert_record(env: &Env, expert: &Address, status: ExpertStatus, data_uri: String) {
-    let key = DataKey::Expert(expert.clone());
-
+/// Skill tags, the bond and the verification expiry are carried over from the existing record; any suspension is cleared
+pub fn set_expert_record(
+    env: &Env,
+    expert: &Address,
+    status: ExpertStatus,
+    data_uri: String,
+    data_hash: BytesN<32>,
+) {
+    let existing = get_expert_record(env, expert);
     let record = ExpertRecord {
         status,
         updated_at: env.ledger().timestamp(),
         data_uri,
+        data_hash,
+        skills: existing.skills,
+        suspended_until: 0,
+        bond: existing.bond,
+        bonded_at: existing.bonded_at,
+        verified_at: existing.verified_at,
+        verified_until: existing.verified_until,
+        handle: existing.handle,
     };
 
+    save_expert_record(env, expert, &record);
+}
+
+/// Save a full expert record as-is, extending its TTL
+pub fn save_expert_record(env: &Env, expert: &Address, record: &ExpertRecord) {
+    let key = DataKey::Expert(expert.clone());
+
     // 1. Save the data
-    env.storage().persistent().set(&key, &record);
+    env.storage().persistent().set(&key, record);
 
     // 2. Extend the TTL
     // This tells the network: "If this data is going to die in less than 2 months,
@@ -75,43 +219,185 @@ pub fn get_expert_record(env: &Env, expert: &Address) -> ExpertRecord {
             .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
     }
 
-    env.storage()
-        .persistent()
-        .get(&key)
+    // Version 1 records outside the directory (bans of never-verified addresses)
+    // are not reached by `migrate`, so they are converted here instead
+    let fields: Option<Map<Symbol, Val>> = env.storage().persistent().get(&key);
+    fields
+        .and_then(|fields| {
+            let val: Val = fields.into_val(env);
+            if fields.len() == ExpertRecordV1::FIELDS {
+                ExpertRecordV1::try_from_val(env, &val)
+                    .ok()
+                    .map(|old| old.into_record(env))
+            } else {
+                ExpertRecord::try_from_val(env, &val).ok()
+            }
+        })
         .unwrap_or(ExpertRecord {
             status: ExpertStatus::Unverified,
             updated_at: 0,
             data_uri: String::from_str(env, ""),
+            data_hash: BytesN::from_array(env, &[0u8; 32]),
+            skills: Vec::new(env),
+            suspended_until: 0,
+            bond: 0,
+            bonded_at: 0,
+            verified_at: 0,
+            verified_until: 0,
+            handle: None,
         })
 }
 
+/// Status as callers see it: a Verified record reads as Suspended until the
+/// suspension runs out, after which it is Verified again without a write,
+/// and as Expired once its verification has lapsed
+pub fn effective_status(env: &Env, record: &ExpertRecord) -> ExpertStatus {
+    if record.status != ExpertStatus::Verified {
+        return record.status;
+    }
+
+    let now = env.ledger().timestamp();
+    if record.suspended_until > now {
+        return ExpertStatus::Suspended;
+    }
+    let expires_at = verification_expiry(env, record);
+    if expires_at != 0 && expires_at <= now {
+        return ExpertStatus::Expired;
+    }
+    ExpertStatus::Verified
+}
+
+/// When a verification lapses, 0 if it never does. Records verified while
+/// there was no period (V1 records included) lapse a period after they were
+/// last verified or renewed once one is set.
+pub fn verification_expiry(env: &Env, record: &ExpertRecord) -> u64 {
+    if record.verified_until != 0 {
+        return record.verified_until;
+    }
+    match get_verification_period(env) {
+        0 => 0,
+        period => record.verified_at.saturating_add(period),
+    }
+}
+
+/// Check whether a record has ever been stored for the expert
+pub fn has_expert_record(env: &Env, expert: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Expert(expert.clone()))
+}
+
 /// Get the expert status
 pub fn get_expert_status(env: &Env, expert: &Address) -> ExpertStatus {
-    get_expert_record(env, expert).status
+    effective_status(env, &get_expert_record(env, expert))
 }
 
 // ... [Expert Directory Index Helpers] ...
 
 /// Add an expert address to the enumerable index and increment the count
+/// Experts already in the index are skipped
 pub fn add_expert_to_index(env: &Env, expert: &Address) {
+    if is_indexed(env, expert) {
+        return;
+    }
+    mark_indexed(env, expert);
+
     let count: u64 = env
         .storage()
         .instance()
         .get(&DataKey::TotalVerifiedCount)
         .unwrap_or(0u64);
 
+    set_index_slot(env, count, expert);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalVerifiedCount, &(count + 1));
+}
+
+/// Point a directory slot at an expert and remember the slot for them
+fn set_index_slot(env: &Env, index: u64, expert: &Address) {
+    let slot_key = DataKey::VerifiedExpertIndex(index);
+
+    env.storage().persistent().set(&slot_key, expert);
+    env.storage()
+        .persistent()
+        .extend_ttl(&slot_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    set_index_position(env, expert, index);
+}
+
+/// Remember which directory slot an expert holds
+pub fn set_index_position(env: &Env, expert: &Address, index: u64) {
+    let position_key = DataKey::IndexPosition(expert.clone());
+    env.storage().persistent().set(&position_key, &index);
     env.storage()
         .persistent()
-        .set(&DataKey::VerifiedExpertIndex(count), expert);
-    env.storage().persistent().extend_ttl(
-        &DataKey::VerifiedExpertIndex(count),
-        LEDGERS_THRESHOLD,
-        LEDGERS_EXTEND_TO,
-    );
+        .extend_ttl(&position_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
 
+/// Get the directory slot an expert holds, if it has been recorded
+pub fn get_index_position(env: &Env, expert: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexPosition(expert.clone()))
+}
+
+/// Drop a directory slot by moving the last slot into it and shrinking the
+/// count. Returns the new count.
+pub fn remove_index_slot(env: &Env, index: u64) -> u64 {
+    let last = get_total_experts(env) - 1;
+    if index < last {
+        if let Some(moved) = get_expert_by_index(env, last) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::VerifiedExpertIndex(index), &moved);
+            env.storage().persistent().extend_ttl(
+                &DataKey::VerifiedExpertIndex(index),
+                LEDGERS_THRESHOLD,
+                LEDGERS_EXTEND_TO,
+            );
+            // Only the expert's own slot moves its position, not a duplicate of it
+            if get_index_position(env, &moved) == Some(last) {
+                set_index_position(env, &moved, index);
+            }
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::VerifiedExpertIndex(last));
     env.storage()
         .instance()
-        .set(&DataKey::TotalVerifiedCount, &(count + 1));
+        .set(&DataKey::TotalVerifiedCount, &last);
+    last
+}
+
+/// Hand an expert's directory slot, if any, to another address
+pub fn replace_in_index(env: &Env, old: &Address, new: &Address) {
+    // Every slot records its position, including those rewritten by `migrate`
+    let position_key = DataKey::IndexPosition(old.clone());
+    let Some(position) = env.storage().persistent().get(&position_key) else {
+        return;
+    };
+
+    env.storage().persistent().remove(&position_key);
+    set_index_slot(env, position, new);
+    mark_indexed(env, new);
+}
+
+/// Check whether an expert already has a directory slot
+pub fn is_indexed(env: &Env, expert: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Indexed(expert.clone()))
+}
+
+/// Record that an expert has a directory slot
+pub fn mark_indexed(env: &Env, expert: &Address) {
+    let key = DataKey::Indexed(expert.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
 }
 
 /// Get the total number of verified experts ever indexed
@@ -123,9 +409,492 @@ pub fn get_total_experts(env: &Env) -> u64 {
 }
 
 /// Get the expert address at the given index
-pub fn get_expert_by_index(env: &Env, index: u64) -> Address {
+pub fn get_expert_by_index(env: &Env, index: u64) -> Option<Address> {
     env.storage()
         .persistent()
         .get(&DataKey::VerifiedExpertIndex(index))
-        .expect("Index out of bounds")
+}
+
+// ... [Soulbound Token Helpers] ...
+
+/// Allocate the next verification token id
+pub fn get_next_token_id(env: &Env) -> u64 {
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenCounter)
+        .unwrap_or(0u64);
+    let next = current + 1;
+    env.storage().instance().set(&DataKey::TokenCounter, &next);
+    next
+}
+
+/// Record the owner of a token and the reverse lookup
+pub fn set_token_owner(env: &Env, token_id: u64, owner: &Address) {
+    let owner_key = DataKey::TokenOwner(token_id);
+    let token_key = DataKey::TokenOf(owner.clone());
+
+    env.storage().persistent().set(&owner_key, owner);
+    env.storage().persistent().set(&token_key, &token_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&owner_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&token_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Remove a burned token and its reverse lookup
+pub fn remove_token_owner(env: &Env, token_id: u64, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenOwner(token_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenOf(owner.clone()));
+}
+
+/// Get the owner of a token
+pub fn get_token_owner(env: &Env, token_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenOwner(token_id))
+}
+
+/// Get the token held by an address
+pub fn get_token_of(env: &Env, owner: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenOf(owner.clone()))
+}
+
+// ... [Skill Index Helpers] ...
+
+/// Get the number of experts listed under a tag
+pub fn get_skill_count(env: &Env, tag: &Symbol) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SkillCount(tag.clone()))
+        .unwrap_or(0u64)
+}
+
+/// Get the expert at the given position in a tag's index
+pub fn get_expert_by_skill_index(env: &Env, tag: &Symbol, index: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SkillIndex(tag.clone(), index))
+}
+
+fn set_skill_slot(env: &Env, tag: &Symbol, index: u64, expert: &Address) {
+    let slot_key = DataKey::SkillIndex(tag.clone(), index);
+    let position_key = DataKey::SkillPosition(tag.clone(), expert.clone());
+
+    env.storage().persistent().set(&slot_key, expert);
+    env.storage().persistent().set(&position_key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&slot_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&position_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+fn set_skill_count(env: &Env, tag: &Symbol, count: u64) {
+    let key = DataKey::SkillCount(tag.clone());
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Append an expert to a tag's index (no-op if already listed)
+pub fn add_expert_to_skill(env: &Env, tag: &Symbol, expert: &Address) {
+    let position_key = DataKey::SkillPosition(tag.clone(), expert.clone());
+    if env.storage().persistent().has(&position_key) {
+        return;
+    }
+
+    let count = get_skill_count(env, tag);
+    set_skill_slot(env, tag, count, expert);
+    set_skill_count(env, tag, count + 1);
+}
+
+/// Remove an expert from a tag's index by moving the last entry into its slot
+pub fn remove_expert_from_skill(env: &Env, tag: &Symbol, expert: &Address) {
+    let position_key = DataKey::SkillPosition(tag.clone(), expert.clone());
+    let Some(index) = env.storage().persistent().get::<_, u64>(&position_key) else {
+        return;
+    };
+
+    let last = get_skill_count(env, tag) - 1;
+    if index != last {
+        if let Some(moved) = get_expert_by_skill_index(env, tag, last) {
+            set_skill_slot(env, tag, index, &moved);
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SkillIndex(tag.clone(), last));
+    env.storage().persistent().remove(&position_key);
+    set_skill_count(env, tag, last);
+}
+
+// ... [Appeal Helpers] ...
+
+/// Save the latest appeal filed by an expert
+pub fn set_appeal(env: &Env, expert: &Address, appeal: &Appeal) {
+    let key = DataKey::Appeal(expert.clone());
+    env.storage().persistent().set(&key, appeal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get the latest appeal filed by an expert
+pub fn get_appeal(env: &Env, expert: &Address) -> Option<Appeal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Appeal(expert.clone()))
+}
+
+/// Delete the expert's appeal
+pub fn remove_appeal(env: &Env, expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Appeal(expert.clone()));
+}
+
+// ... [Application Helpers] ...
+
+/// Save the latest application filed by an expert
+pub fn set_application(env: &Env, expert: &Address, application: &Application) {
+    let key = DataKey::Application(expert.clone());
+    env.storage().persistent().set(&key, application);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get the latest application filed by an expert
+pub fn get_application(env: &Env, expert: &Address) -> Option<Application> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Application(expert.clone()))
+}
+
+/// Get the number of applications awaiting review
+pub fn get_pending_application_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingApplicationCount)
+        .unwrap_or(0u64)
+}
+
+/// Get the applicant at the given position in the review queue
+pub fn get_pending_application_by_index(env: &Env, index: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingApplicationIndex(index))
+}
+
+fn set_pending_application_slot(env: &Env, index: u64, expert: &Address) {
+    let slot_key = DataKey::PendingApplicationIndex(index);
+    let position_key = DataKey::PendingApplicationPosition(expert.clone());
+
+    env.storage().persistent().set(&slot_key, expert);
+    env.storage().persistent().set(&position_key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&slot_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&position_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Append an applicant to the review queue
+pub fn add_pending_application(env: &Env, expert: &Address) {
+    let count = get_pending_application_count(env);
+    set_pending_application_slot(env, count, expert);
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingApplicationCount, &(count + 1));
+}
+
+/// Remove an applicant from the review queue by moving the last entry into its slot
+pub fn remove_pending_application(env: &Env, expert: &Address) {
+    let position_key = DataKey::PendingApplicationPosition(expert.clone());
+    let Some(index) = env.storage().persistent().get::<_, u64>(&position_key) else {
+        return;
+    };
+
+    let last = get_pending_application_count(env) - 1;
+    if index != last {
+        if let Some(moved) = get_pending_application_by_index(env, last) {
+            set_pending_application_slot(env, index, &moved);
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingApplicationIndex(last));
+    env.storage().persistent().remove(&position_key);
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingApplicationCount, &last);
+}
+
+// ... [Bond Helpers] ...
+
+/// Set the verification bond configuration
+pub fn set_bond_config(env: &Env, config: &BondConfig) {
+    env.storage().instance().set(&DataKey::BondConfig, config);
+}
+
+/// Get the verification bond configuration
+pub fn get_bond_config(env: &Env) -> Option<BondConfig> {
+    env.storage().instance().get(&DataKey::BondConfig)
+}
+
+/// Get the sum of all bonds held by the registry
+pub fn get_bonded_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BondedTotal)
+        .unwrap_or(0i128)
+}
+
+/// Set the sum of all bonds held by the registry
+pub fn set_bonded_total(env: &Env, total: i128) {
+    env.storage().instance().set(&DataKey::BondedTotal, &total);
+}
+
+// ... [Verification Period Helpers] ...
+
+/// Set how long a verification stays valid, in seconds (0 = forever)
+pub fn set_verification_period(env: &Env, period: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::VerificationPeriod, &period);
+}
+
+/// Get how long a verification stays valid, in seconds (0 = forever)
+pub fn get_verification_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::VerificationPeriod)
+        .unwrap_or(0u64)
+}
+
+// ... [Attestation Helpers] ...
+
+/// Get every attestation recorded for an expert
+pub fn get_attestations(env: &Env, expert: &Address) -> Vec<Attestation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Attestations(expert.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replace the attestations recorded for an expert
+pub fn set_attestations(env: &Env, expert: &Address, attestations: &Vec<Attestation>) {
+    let key = DataKey::Attestations(expert.clone());
+    env.storage().persistent().set(&key, attestations);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Set the attestations required for verification
+pub fn set_attestation_policy(env: &Env, policy: &AttestationPolicy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AttestationPolicy, policy);
+}
+
+/// Get the attestations required for verification
+pub fn get_attestation_policy(env: &Env) -> Option<AttestationPolicy> {
+    env.storage().instance().get(&DataKey::AttestationPolicy)
+}
+
+// ... [URI Policy Helpers] ...
+
+/// Set the policy URIs are checked against
+pub fn set_uri_policy(env: &Env, policy: &UriPolicy) {
+    env.storage().instance().set(&DataKey::UriPolicy, policy);
+}
+
+/// Get the policy URIs are checked against, if one was set
+pub fn get_uri_policy(env: &Env) -> Option<UriPolicy> {
+    env.storage().instance().get(&DataKey::UriPolicy)
+}
+
+// ... [Pause Helpers] ...
+
+/// Pause or resume a group of entrypoints
+pub fn set_paused(env: &Env, group: PauseGroup, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Paused(group), &paused);
+}
+
+/// Check whether a group of entrypoints is paused
+pub fn is_paused(env: &Env, group: PauseGroup) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused(group))
+        .unwrap_or(false)
+}
+
+// ... [Handle Helpers] ...
+
+/// Record who holds a handle
+pub fn set_handle_claim(env: &Env, handle: &String, claim: &HandleClaim) {
+    let key = DataKey::Handle(handle.clone());
+    env.storage().persistent().set(&key, claim);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get who holds a handle, if anyone
+pub fn get_handle_claim(env: &Env, handle: &String) -> Option<HandleClaim> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Handle(handle.clone()))
+}
+
+/// Free a handle for anyone to claim
+pub fn remove_handle_claim(env: &Env, handle: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Handle(handle.clone()));
+}
+
+// ... [Council Helpers] ...
+
+/// Install or replace the council
+pub fn set_council(env: &Env, council: &Council) {
+    env.storage().instance().set(&DataKey::Council, council);
+}
+
+/// Remove the council
+pub fn remove_council(env: &Env) {
+    env.storage().instance().remove(&DataKey::Council);
+}
+
+/// Get the council, if one is installed
+pub fn get_council(env: &Env) -> Option<Council> {
+    env.storage().instance().get(&DataKey::Council)
+}
+
+/// Allocate the next proposal id
+pub fn get_next_proposal_id(env: &Env) -> u64 {
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ProposalCounter)
+        .unwrap_or(0u64);
+    let next = current + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::ProposalCounter, &next);
+    next
+}
+
+/// Save a proposal, extending its TTL
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get a proposal by id
+pub fn get_proposal(env: &Env, id: u64) -> Option<Proposal> {
+    env.storage().persistent().get(&DataKey::Proposal(id))
+}
+
+// ... [Status History Helpers] ...
+
+/// Get the status changes recorded for an expert, oldest first
+pub fn get_status_history(env: &Env, expert: &Address) -> Vec<StatusChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusHistory(expert.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replace the status changes recorded for an expert
+pub fn set_status_history(env: &Env, expert: &Address, history: &Vec<StatusChange>) {
+    let key = DataKey::StatusHistory(expert.clone());
+    env.storage().persistent().set(&key, history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+// ... [Identity Migration Helpers] ...
+
+/// Record where a migrated expert moved to
+pub fn set_migrated_to(env: &Env, old: &Address, new: &Address) {
+    let key = DataKey::MigratedTo(old.clone());
+    env.storage().persistent().set(&key, new);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get where a migrated expert moved to
+pub fn get_migrated_to(env: &Env, old: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MigratedTo(old.clone()))
+}
+
+/// Save a pending admin recovery for an expert
+pub fn set_recovery(env: &Env, old: &Address, request: &RecoveryRequest) {
+    let key = DataKey::Recovery(old.clone());
+    env.storage().persistent().set(&key, request);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get the pending admin recovery for an expert
+pub fn get_recovery(env: &Env, old: &Address) -> Option<RecoveryRequest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recovery(old.clone()))
+}
+
+/// Drop the pending admin recovery for an expert
+pub fn remove_recovery(env: &Env, old: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Recovery(old.clone()));
+}
+
+/// Record the council's approval to recover an expert to `new`
+pub fn set_recovery_approval(env: &Env, old: &Address, new: &Address) {
+    let key = DataKey::RecoveryApproval(old.clone());
+    env.storage().persistent().set(&key, new);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get the address the council approved recovering an expert to
+pub fn get_recovery_approval(env: &Env, old: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecoveryApproval(old.clone()))
+}
+
+/// Drop the council's approval to recover an expert
+pub fn remove_recovery_approval(env: &Env, old: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RecoveryApproval(old.clone()));
 }
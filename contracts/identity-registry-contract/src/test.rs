@@ -3,13 +3,28 @@
 extern crate std;
 
 use crate::error::RegistryError;
-use crate::{storage, types::ExpertStatus};
+use crate::{
+    storage,
+    types::{ExpertStatus, Role},
+};
 use crate::{IdentityRegistryContract, IdentityRegistryContractClient};
 use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
 use soroban_sdk::{
-    testutils::Address as _, vec, Address, Env, IntoVal, String, Symbol, TryIntoVal,
+    testutils::Address as _, vec, Address, BytesN, Env, IntoVal, String, Symbol, TryIntoVal,
 };
 
+// Registry built with `cargo build --release --target wasm32v1-none`, used as the upgrade target
+const REGISTRY_WASM: &[u8] = include_bytes!("../test_fixtures/identity_registry_contract.wasm");
+
+// Version 1 registry built from the baseline commit, used as the upgrade source
+mod v1 {
+    soroban_sdk::contractimport!(file = "test_fixtures/identity_registry_contract_v1.wasm");
+}
+
+fn profile_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xabu8; 32])
+}
+
 #[test]
 fn test_initialization() {
     let env = Env::default();
@@ -41,7 +56,7 @@ fn test_data_uri_persisted_on_verify() {
     let uri = String::from_str(&env, "ipfs://persisted");
 
     client.init(&admin);
-    client.add_expert(&expert, &uri);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
 
     // Read storage as contract and assert data_uri persisted
     env.as_contract(&contract_id, || {
@@ -64,10 +79,10 @@ fn test_update_profile_updates_uri_and_emits_event() {
     let uri2 = String::from_str(&env, "ipfs://updated");
 
     client.init(&admin);
-    client.add_expert(&expert, &uri1);
+    client.add_expert(&admin, &expert, &uri1, &profile_hash(&env));
 
     // Update profile URI
-    client.update_profile(&expert, &uri2);
+    client.update_profile(&expert, &uri2, &profile_hash(&env));
 
     // Assert record updated
     env.as_contract(&contract_id, || {
@@ -78,6 +93,47 @@ fn test_update_profile_updates_uri_and_emits_event() {
     // Event assertion skipped to avoid flakiness in event buffers
 }
 
+#[test]
+fn test_profile_hash_is_stored_and_verifiable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://initial");
+    let new_uri = String::from_str(&env, "ipfs://updated");
+    let new_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.init(&admin);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+
+    assert!(client.verify_profile_hash(&expert, &profile_hash(&env)));
+    assert!(!client.verify_profile_hash(&expert, &new_hash));
+    // No record means nothing to match, not even the zero hash
+    assert!(!client.verify_profile_hash(&stranger, &BytesN::from_array(&env, &[0u8; 32])));
+
+    client.update_profile(&expert, &new_uri, &new_hash);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let payload: crate::events::ProfileUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(payload.expert, expert);
+    assert_eq!(payload.new_uri, new_uri);
+    assert_eq!(payload.new_hash, new_hash);
+
+    assert!(client.verify_profile_hash(&expert, &new_hash));
+    assert!(!client.verify_profile_hash(&expert, &profile_hash(&env)));
+
+    // Batch adds store a zero placeholder, which never verifies
+    let batched = Address::generate(&env);
+    client.batch_add_experts(&admin, &vec![&env, batched.clone()]);
+    assert!(!client.verify_profile_hash(&batched, &BytesN::from_array(&env, &[0u8; 32])));
+}
+
 #[test]
 fn test_update_profile_rejections() {
     let env = Env::default();
@@ -92,18 +148,18 @@ fn test_update_profile_rejections() {
 
     // NotVerified when updating without being verified
     let new_uri = String::from_str(&env, "ipfs://new");
-    let res = client.try_update_profile(&unverified, &new_uri);
+    let res = client.try_update_profile(&unverified, &new_uri, &profile_hash(&env));
     assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
 
     // Verify then try overlong uri
     let expert = Address::generate(&env);
     let ok_uri = String::from_str(&env, "ipfs://ok");
-    client.add_expert(&expert, &ok_uri);
+    client.add_expert(&admin, &expert, &ok_uri, &profile_hash(&env));
 
     // Build >64 length string
     let long_str = "a".repeat(65);
     let long_uri = String::from_str(&env, long_str.as_str());
-    let res2 = client.try_update_profile(&expert, &long_uri);
+    let res2 = client.try_update_profile(&expert, &long_uri, &profile_hash(&env));
     assert_eq!(res2, Err(Ok(RegistryError::UriTooLong)));
 }
 
@@ -115,6 +171,7 @@ fn test_batch_verification_no_admin() {
     let contract_id = env.register(IdentityRegistryContract, ());
     let client = IdentityRegistryContractClient::new(&env, &contract_id);
 
+    let admin = Address::generate(&env);
     let experts = vec![
         &env,
         Address::generate(&env),
@@ -122,7 +179,7 @@ fn test_batch_verification_no_admin() {
         Address::generate(&env),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 }
 
 #[test]
@@ -151,7 +208,7 @@ fn test_batch_verification_check_status() {
         e5.clone(),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 
     env.as_contract(&contract_id, || {
         assert_eq!(
@@ -222,7 +279,7 @@ fn test_batch_verification_max_vec() {
         e4.clone(),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 }
 
 #[test]
@@ -239,7 +296,7 @@ fn test_add_expert() {
     client.init(&admin);
 
     let data_uri = String::from_str(&env, "ipfs://profile1");
-    let res = client.try_add_expert(&expert, &data_uri);
+    let res = client.try_add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
     assert!(res.is_ok());
 
     assert_eq!(
@@ -250,7 +307,13 @@ fn test_add_expert() {
                 function: AuthorizedFunction::Contract((
                     contract_id.clone(),
                     Symbol::new(&env, "add_expert"),
-                    (expert.clone(), data_uri.clone()).into_val(&env)
+                    (
+                        admin.clone(),
+                        expert.clone(),
+                        data_uri.clone(),
+                        profile_hash(&env)
+                    )
+                        .into_val(&env)
                 )),
                 sub_invocations: std::vec![]
             }
@@ -271,7 +334,7 @@ fn test_add_expert_unauthorized() {
 
     client.init(&admin);
     let data_uri = String::from_str(&env, "ipfs://unauth");
-    client.add_expert(&expert, &data_uri);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
 }
 
 #[test]
@@ -287,7 +350,7 @@ fn test_expert_status_changed_event() {
 
     client.init(&admin);
     let data_uri = String::from_str(&env, "ipfs://event");
-    client.add_expert(&expert, &data_uri);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
 
     let events = env.events().all();
     let event = events.last().unwrap();
@@ -313,21 +376,21 @@ fn test_ban_expert() {
     // Verify the expert first
     env.mock_all_auths();
     let data_uri = String::from_str(&env, "ipfs://ban");
-    client.add_expert(&expert, &data_uri);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
 
     // Verify status is Verified
     let status = client.get_status(&expert);
     assert_eq!(status, ExpertStatus::Verified);
 
     // Ban the expert (should succeed)
-    client.ban_expert(&expert);
+    client.ban_expert(&admin, &expert);
 
     // Check that status is now Banned
     let status = client.get_status(&expert);
     assert_eq!(status, ExpertStatus::Banned);
 
     // Test: Try to ban again (should fail with AlreadyBanned)
-    let result = client.try_ban_expert(&expert);
+    let result = client.try_ban_expert(&admin, &expert);
     assert_eq!(result, Err(Ok(RegistryError::AlreadyBanned)));
 }
 
@@ -345,13 +408,13 @@ fn test_ban_expert_unauthorized() {
 
     env.mock_all_auths();
     let data_uri = String::from_str(&env, "ipfs://ban-unauth");
-    client.add_expert(&expert, &data_uri);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
 
     env.mock_all_auths_allowing_non_root_auth();
 
     env.mock_auths(&[]);
 
-    client.ban_expert(&expert);
+    client.ban_expert(&admin, &expert);
 }
 
 #[test]
@@ -372,7 +435,7 @@ fn test_ban_unverified_expert() {
 
     // Ban an expert who was never verified (should still succeed)
     env.mock_all_auths();
-    client.ban_expert(&expert);
+    client.ban_expert(&admin, &expert);
 
     // Status should be Banned now
     let status = client.get_status(&expert);
@@ -399,9 +462,9 @@ fn test_ban_expert_workflow() {
     let uri1 = String::from_str(&env, "ipfs://u1");
     let uri2 = String::from_str(&env, "ipfs://u2");
     let uri3 = String::from_str(&env, "ipfs://u3");
-    client.add_expert(&expert1, &uri1);
-    client.add_expert(&expert2, &uri2);
-    client.add_expert(&expert3, &uri3);
+    client.add_expert(&admin, &expert1, &uri1, &profile_hash(&env));
+    client.add_expert(&admin, &expert2, &uri2, &profile_hash(&env));
+    client.add_expert(&admin, &expert3, &uri3, &profile_hash(&env));
 
     // Check all are verified
     assert_eq!(client.get_status(&expert1), ExpertStatus::Verified);
@@ -409,7 +472,7 @@ fn test_ban_expert_workflow() {
     assert_eq!(client.get_status(&expert3), ExpertStatus::Verified);
 
     // Ban expert2
-    client.ban_expert(&expert2);
+    client.ban_expert(&admin, &expert2);
 
     // Verify expert2 is banned, others remain verified
     assert_eq!(client.get_status(&expert1), ExpertStatus::Verified);
@@ -417,7 +480,7 @@ fn test_ban_expert_workflow() {
     assert_eq!(client.get_status(&expert3), ExpertStatus::Verified);
 
     // Ban expert1
-    client.ban_expert(&expert1);
+    client.ban_expert(&admin, &expert1);
 
     // Verify expert1 is now banned
     assert_eq!(client.get_status(&expert1), ExpertStatus::Banned);
@@ -431,12 +494,13 @@ fn test_ban_before_contract_initialized() {
     let contract_id = env.register(IdentityRegistryContract, ());
     let client = IdentityRegistryContractClient::new(&env, &contract_id);
 
+    let admin = Address::generate(&env);
     let expert = Address::generate(&env);
 
     env.mock_all_auths();
 
     // Try to ban without initializing (should fail)
-    let result = client.try_ban_expert(&expert);
+    let result = client.try_ban_expert(&admin, &expert);
     assert_eq!(result, Err(Ok(RegistryError::NotInitialized)));
 }
 
@@ -459,11 +523,11 @@ fn test_complete_expert_lifecycle() {
 
     // 2. Verify the expert
     let data_uri = String::from_str(&env, "ipfs://life");
-    client.add_expert(&expert, &data_uri);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 
     // 3. Ban the expert
-    client.ban_expert(&expert);
+    client.ban_expert(&admin, &expert);
     assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
 }
 
@@ -480,19 +544,19 @@ fn test_getters() {
 
     // Test 1: Check is_verified on a random address (should be false)
     let random_address = Address::generate(&env);
-    assert_eq!(client.is_verified(&random_address), false);
+    assert!(!client.is_verified(&random_address));
     assert_eq!(client.get_status(&random_address), ExpertStatus::Unverified);
 
     // Test 2: Verify an expert and check is_verified (should be true)
     let expert = Address::generate(&env);
     let data_uri = String::from_str(&env, "ipfs://getters");
-    client.add_expert(&expert, &data_uri);
-    assert_eq!(client.is_verified(&expert), true);
+    client.add_expert(&admin, &expert, &data_uri, &profile_hash(&env));
+    assert!(client.is_verified(&expert));
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 
     // Test 3: Ban the expert and check is_verified (should be false)
-    client.ban_expert(&expert);
-    assert_eq!(client.is_verified(&expert), false);
+    client.ban_expert(&admin, &expert);
+    assert!(!client.is_verified(&expert));
     assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
 }
 
@@ -515,9 +579,9 @@ fn test_expert_directory_enumeration() {
     let uri1 = String::from_str(&env, "ipfs://e1");
     let uri2 = String::from_str(&env, "ipfs://e2");
     let uri3 = String::from_str(&env, "ipfs://e3");
-    client.add_expert(&expert1, &uri1);
-    client.add_expert(&expert2, &uri2);
-    client.add_expert(&expert3, &uri3);
+    client.add_expert(&admin, &expert1, &uri1, &profile_hash(&env));
+    client.add_expert(&admin, &expert2, &uri2, &profile_hash(&env));
+    client.add_expert(&admin, &expert3, &uri3, &profile_hash(&env));
 
     // Total should be 3
     assert_eq!(client.get_total_experts(), 3u64);
@@ -542,13 +606,13 @@ fn test_expert_directory_no_duplicates_on_reverify() {
     client.init(&admin);
 
     let uri = String::from_str(&env, "ipfs://expert");
-    client.add_expert(&expert, &uri);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
 
     // Total is 1
     assert_eq!(client.get_total_experts(), 1u64);
 
     // Re-verifying an already verified expert returns AlreadyVerified
-    let result = client.try_add_expert(&expert, &uri);
+    let result = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
     assert_eq!(result, Err(Ok(RegistryError::AlreadyVerified)));
 
     // Total remains 1 — no duplicate in the index
@@ -571,7 +635,7 @@ fn test_expert_directory_via_batch_add() {
     client.init(&admin);
 
     let experts = vec![&env, expert1.clone(), expert2.clone(), expert3.clone()];
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 
     // Total should be 3
     assert_eq!(client.get_total_experts(), 3u64);
@@ -581,3 +645,2450 @@ fn test_expert_directory_via_batch_add() {
     assert_eq!(client.get_expert_by_index(&1u64), expert2);
     assert_eq!(client.get_expert_by_index(&2u64), expert3);
 }
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+
+    // Nothing to accept yet
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(RegistryError::NoPendingAdmin)));
+
+    client.transfer_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // Old admin keeps control until the transfer is accepted
+    assert!(client.has_role(&Role::SuperAdmin, &admin));
+    assert!(!client.has_role(&Role::SuperAdmin, &new_admin));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+
+    assert!(client.has_role(&Role::SuperAdmin, &new_admin));
+    assert!(!client.has_role(&Role::SuperAdmin, &admin));
+
+    // The old admin can no longer verify experts
+    let uri = String::from_str(&env, "ipfs://x");
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    client.add_expert(&new_admin, &expert, &uri, &profile_hash(&env));
+}
+
+#[test]
+fn test_roles_gate_verify_and_ban() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert1 = Address::generate(&env);
+    let expert2 = Address::generate(&env);
+    client.init(&admin);
+
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+    client.grant_role(&admin, &Role::Moderator, &moderator);
+    assert!(client.has_role(&Role::Verifier, &verifier));
+    assert!(!client.has_role(&Role::Moderator, &verifier));
+
+    let uri = String::from_str(&env, "ipfs://x");
+
+    // Moderators cannot add experts, Verifiers can
+    let res = client.try_add_expert(&moderator, &expert1, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    client.add_expert(&verifier, &expert1, &uri, &profile_hash(&env));
+    client.batch_add_experts(&verifier, &vec![&env, expert2.clone()]);
+
+    // Verifiers cannot ban, Moderators can
+    let res = client.try_ban_expert(&verifier, &expert1);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    let res = client.try_batch_ban_experts(&verifier, &vec![&env, expert2.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    client.ban_expert(&moderator, &expert1);
+    client.batch_ban_experts(&moderator, &vec![&env, expert2.clone()]);
+    assert_eq!(client.get_status(&expert1), ExpertStatus::Banned);
+    assert_eq!(client.get_status(&expert2), ExpertStatus::Banned);
+
+    // Only SuperAdmins manage roles
+    let res = client.try_grant_role(&verifier, &Role::Moderator, &verifier);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    let super_admin = Address::generate(&env);
+    client.grant_role(&admin, &Role::SuperAdmin, &super_admin);
+    client.revoke_role(&super_admin, &Role::Verifier, &verifier);
+    assert!(!client.has_role(&Role::Verifier, &verifier));
+
+    let expert3 = Address::generate(&env);
+    let res = client.try_add_expert(&verifier, &expert3, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+}
+
+#[test]
+fn test_role_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    client.init(&admin);
+
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "role_granted"));
+
+    client.revoke_role(&admin, &Role::Verifier, &verifier);
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "role_revoked"));
+}
+
+#[test]
+fn test_upgrade_from_v1_fixture_and_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Deploy the version 1 registry and fill it through its own entrypoints
+    let contract_id = env.register(v1::WASM, ());
+    let old = v1::Client::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let rebanned = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://v1");
+    old.init(&admin);
+    old.add_expert(&expert, &uri);
+    old.batch_add_experts(&vec![&env, rebanned.clone()]);
+    old.ban_expert(&rebanned);
+    old.add_expert(&rebanned, &uri);
+    // Banning a never-verified address leaves a record outside the directory
+    old.ban_expert(&outsider);
+    assert_eq!(old.get_total_experts(), 3);
+
+    // Version 1 has no upgrade entrypoint, so swap the code from inside the contract
+    let wasm_hash = env.deployer().upload_contract_wasm(REGISTRY_WASM);
+    env.as_contract(&contract_id, || {
+        env.deployer()
+            .update_current_contract_wasm(wasm_hash.clone())
+    });
+
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_version(), 1);
+    assert_eq!(client.migrate(&0u64, &2u32), Some(2));
+    assert_eq!(client.migrate(&2u64, &2u32), None);
+    assert_eq!(client.get_version(), 2);
+
+    // Every record written by version 1 reads back in the current layout
+    assert!(client.is_verified(&expert));
+    assert!(client.is_verified(&rebanned));
+    assert_eq!(client.get_status(&outsider), ExpertStatus::Banned);
+    assert_eq!(client.get_expert_by_index(&0u64), expert);
+    assert_eq!(client.balance(&expert), 1);
+    assert_eq!(client.balance(&rebanned), 1);
+    assert_eq!(client.balance(&outsider), 0);
+
+    // The current code keeps working on the migrated storage
+    client.update_profile(&expert, &uri, &profile_hash(&env));
+    assert!(client.verify_profile_hash(&expert, &profile_hash(&env)));
+    client.ban_expert(&admin, &expert);
+    assert!(!client.is_verified(&expert));
+    client.file_appeal(&outsider, &String::from_str(&env, "ipfs://evidence"));
+
+    // The new code can be upgraded again by the admin
+    client.upgrade(&wasm_hash);
+    assert_eq!(env.auths()[0].0, admin);
+}
+
+#[test]
+fn test_upgrade_admin_only() {
+    let env = Env::default();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(REGISTRY_WASM);
+    let res = client.try_upgrade(&wasm_hash);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_migrate_rewrites_v1_expert_records() {
+    use crate::migration::ExpertRecordV1;
+    use crate::storage::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let other = Address::generate(&env);
+    let late = Address::generate(&env);
+    let banned = Address::generate(&env);
+    client.init(&admin);
+
+    // Simulate storage left behind by a version 1 deployment
+    let uri = String::from_str(&env, "ipfs://legacy");
+    env.as_contract(&contract_id, || {
+        let old = ExpertRecordV1 {
+            status: ExpertStatus::Verified,
+            updated_at: 42,
+            data_uri: uri.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expert(expert.clone()), &old);
+        // Version 1 banned never-verified addresses without indexing them
+        let old = ExpertRecordV1 {
+            status: ExpertStatus::Banned,
+            updated_at: 43,
+            data_uri: String::from_str(&env, ""),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expert(banned.clone()), &old);
+        let old = ExpertRecordV1 {
+            status: ExpertStatus::Verified,
+            updated_at: 44,
+            data_uri: uri.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expert(other.clone()), &old);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expert(late.clone()), &old);
+        // Version 1 gave a re-verified expert a second slot
+        for (index, address) in [&expert, &other, &expert, &late].into_iter().enumerate() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::VerifiedExpertIndex(index as u64), address);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalVerifiedCount, &4u64);
+        env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(client.get_version(), 1);
+
+    // The version only moves once the last page is done
+    assert_eq!(client.migrate(&0u64, &1u32), Some(1));
+    assert_eq!(client.get_version(), 1);
+    // A page past the cursor does not leave a gap behind it; the duplicate
+    // slot it finds is filled with the last one
+    assert_eq!(client.migrate(&2u64, &1u32), Some(1));
+    assert_eq!(client.migrate(&1u64, &10u32), None);
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(client.balance(&other), 1);
+    assert_eq!(client.balance(&late), 1);
+
+    // One slot per expert is left
+    assert_eq!(client.get_total_experts(), 3);
+    let page = client.list_experts(&0u64, &10u32, &None);
+    assert_eq!(page.experts.len(), 3);
+    assert_eq!(page.experts.get(2).unwrap().0, late);
+
+    env.as_contract(&contract_id, || {
+        let rec = storage::get_expert_record(&env, &expert);
+        assert_eq!(rec.status, ExpertStatus::Verified);
+        assert_eq!(rec.updated_at, 42);
+        assert_eq!(rec.data_uri, uri);
+    });
+
+    // Verified experts from version 1 receive their verification token
+    assert_eq!(client.balance(&expert), 1);
+
+    // Records outside the directory still read, and convert when next written
+    assert_eq!(client.get_status(&banned), ExpertStatus::Banned);
+    assert!(!client.is_verified(&banned));
+    client.file_appeal(&banned, &String::from_str(&env, "ipfs://evidence"));
+    assert_eq!(client.get_appeal(&banned).banned_at, 43);
+
+    // Migrated slots know their position, so a key change keeps the slot
+    let new_key = Address::generate(&env);
+    client.migrate_identity(&other, &new_key);
+    assert_eq!(client.get_expert_by_index(&1u64), new_key);
+
+    // Migrating twice is rejected
+    let res = client.try_migrate(&0u64, &10u32);
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_sbt_minted_on_verify_and_burned_on_ban() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://profile");
+    client.init(&admin);
+
+    assert_eq!(client.balance(&expert), 0);
+    assert_eq!(
+        client.try_token_of(&expert),
+        Err(Ok(RegistryError::TokenNotFound))
+    );
+
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    let token_id = client.token_of(&expert);
+    assert_eq!(token_id, 1);
+    assert_eq!(client.balance(&expert), 1);
+    assert_eq!(client.owner_of(&token_id), expert);
+    assert_eq!(client.token_uri(&token_id), uri);
+
+    // Metadata follows the profile
+    let new_uri = String::from_str(&env, "ipfs://profile-v2");
+    client.update_profile(&expert, &new_uri, &profile_hash(&env));
+    assert_eq!(client.token_uri(&token_id), new_uri);
+
+    client.ban_expert(&admin, &expert);
+    assert_eq!(client.balance(&expert), 0);
+    assert_eq!(
+        client.try_owner_of(&token_id),
+        Err(Ok(RegistryError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_token_uri(&token_id),
+        Err(Ok(RegistryError::TokenNotFound))
+    );
+
+    // Reinstatement issues a fresh token
+    client.file_appeal(&expert, &String::from_str(&env, "ipfs://evidence"));
+    client.resolve_appeal(&admin, &expert, &true);
+    assert_eq!(client.token_of(&expert), 2);
+}
+
+#[test]
+fn test_sbt_via_batch_and_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    client.init(&admin);
+
+    client.batch_add_experts(&admin, &vec![&env, e1.clone(), e2.clone()]);
+    assert_eq!(client.token_of(&e1), 1);
+    assert_eq!(client.token_of(&e2), 2);
+
+    client.batch_ban_experts(&admin, &vec![&env, e1.clone()]);
+
+    // The burn is published just before the status change
+    let events = env.events().all();
+    let burn = events.get(events.len() - 2).unwrap();
+    assert_eq!(burn.0, contract_id);
+    let topic: Symbol = burn.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "sbt_burned"));
+
+    assert_eq!(client.balance(&e1), 0);
+    assert_eq!(client.balance(&e2), 1);
+}
+
+#[test]
+fn test_sbt_is_non_transferable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+    let token_id = client.token_of(&expert);
+
+    assert_eq!(
+        client.try_transfer(&expert, &other, &token_id),
+        Err(Ok(RegistryError::SoulboundNonTransferable))
+    );
+    assert_eq!(
+        client.try_transfer_from(&other, &expert, &other, &token_id),
+        Err(Ok(RegistryError::SoulboundNonTransferable))
+    );
+    assert_eq!(
+        client.try_approve(&expert, &other, &token_id),
+        Err(Ok(RegistryError::SoulboundNonTransferable))
+    );
+    assert_eq!(client.owner_of(&token_id), expert);
+}
+
+#[test]
+fn test_get_expert_by_index_out_of_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let res = client.try_get_expert_by_index(&0u64);
+    assert_eq!(res, Err(Ok(RegistryError::ExpertNotFound)));
+}
+
+#[test]
+fn test_list_experts_pagination_and_filter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let uri = String::from_str(&env, "ipfs://expert");
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let e3 = Address::generate(&env);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e3, &uri, &profile_hash(&env));
+    client.ban_expert(&admin, &e2);
+
+    // Unfiltered, two per page
+    let page = client.list_experts(&0u64, &2u32, &None);
+    assert_eq!(page.experts.len(), 2);
+    assert_eq!(page.experts.get(0).unwrap().0, e1);
+    assert_eq!(page.experts.get(1).unwrap().0, e2);
+    assert_eq!(page.experts.get(1).unwrap().1.status, ExpertStatus::Banned);
+    assert_eq!(page.next_cursor, Some(2));
+
+    let page = client.list_experts(&2u64, &2u32, &None);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, e3);
+    assert_eq!(page.next_cursor, None);
+
+    // Filtering out banned experts
+    let page = client.list_experts(&0u64, &10u32, &Some(ExpertStatus::Verified));
+    assert_eq!(page.experts.len(), 2);
+    assert_eq!(page.experts.get(0).unwrap().0, e1);
+    assert_eq!(page.experts.get(1).unwrap().0, e3);
+    assert_eq!(page.next_cursor, None);
+
+    // Past the end yields an empty final page
+    let page = client.list_experts(&10u64, &5u32, &None);
+    assert_eq!(page.experts.len(), 0);
+    assert_eq!(page.next_cursor, None);
+
+    // A banned expert cannot simply be verified again
+    let res = client.try_add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyBanned)));
+
+    // Reinstating them on appeal does not duplicate the directory entry
+    client.file_appeal(&e2, &String::from_str(&env, "ipfs://evidence"));
+    client.resolve_appeal(&admin, &e2, &true);
+    assert_eq!(client.get_total_experts(), 3u64);
+}
+
+#[test]
+fn test_list_experts_limit_is_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let cap = crate::contract::MAX_PAGE_SIZE;
+    let mut experts = vec![&env];
+    for _ in 0..(cap + 5) {
+        experts.push_back(Address::generate(&env));
+        if experts.len() == 20 {
+            client.batch_add_experts(&admin, &experts);
+            experts = vec![&env];
+        }
+    }
+    if !experts.is_empty() {
+        client.batch_add_experts(&admin, &experts);
+    }
+
+    let page = client.list_experts(&0u64, &u32::MAX, &None);
+    assert_eq!(page.experts.len(), cap);
+    assert_eq!(page.next_cursor, Some(cap as u64));
+
+    let page = client.list_experts(&(cap as u64), &u32::MAX, &None);
+    assert_eq!(page.experts.len(), 5);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_skills_index_and_lookup() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let uri = String::from_str(&env, "ipfs://expert");
+    let rust = Symbol::new(&env, "rust");
+    let audit = Symbol::new(&env, "audit");
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let e3 = Address::generate(&env);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e3, &uri, &profile_hash(&env));
+
+    // Expert-managed and admin-managed tags
+    client.set_skills(&e1, &e1, &vec![&env, rust.clone(), audit.clone()]);
+    client.set_skills(&admin, &e2, &vec![&env, rust.clone()]);
+    client.set_skills(&e3, &e3, &vec![&env, rust.clone()]);
+    assert_eq!(client.get_status(&e1), ExpertStatus::Verified);
+
+    let page = client.get_experts_by_skill(&rust, &0u64, &2u32);
+    assert_eq!(page.experts.len(), 2);
+    assert_eq!(page.experts.get(0).unwrap().0, e1);
+    assert_eq!(page.experts.get(1).unwrap().0, e2);
+    assert_eq!(
+        page.experts.get(0).unwrap().1.skills,
+        vec![&env, rust.clone(), audit.clone()]
+    );
+    assert_eq!(page.next_cursor, Some(2));
+
+    let page = client.get_experts_by_skill(&rust, &2u64, &2u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, e3);
+    assert_eq!(page.next_cursor, None);
+
+    // Changing tags moves the expert between indexes
+    client.set_skills(&e1, &e1, &vec![&env, audit.clone()]);
+    let page = client.get_experts_by_skill(&rust, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 2);
+    assert_eq!(page.experts.get(0).unwrap().0, e3);
+    assert_eq!(page.experts.get(1).unwrap().0, e2);
+
+    // Banning drops the expert from every tag; an accepted appeal restores them
+    client.ban_expert(&admin, &e1);
+    let page = client.get_experts_by_skill(&audit, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 0);
+
+    client.file_appeal(&e1, &String::from_str(&env, "ipfs://evidence"));
+    client.resolve_appeal(&admin, &e1, &true);
+    let page = client.get_experts_by_skill(&audit, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, e1);
+
+    // Profile updates keep the tags
+    client.update_profile(
+        &e1,
+        &String::from_str(&env, "ipfs://new"),
+        &profile_hash(&env),
+    );
+    let page = client.list_experts(&0u64, &1u32, &None);
+    assert_eq!(page.experts.get(0).unwrap().1.skills, vec![&env, audit]);
+}
+
+#[test]
+fn test_set_skills_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.init(&admin);
+
+    let rust = Symbol::new(&env, "rust");
+
+    // Only verified experts carry tags
+    let res = client.try_set_skills(&expert, &expert, &vec![&env, rust.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+
+    // Someone else without the Verifier role
+    let res = client.try_set_skills(&stranger, &expert, &vec![&env, rust.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    let res = client.try_set_skills(&expert, &expert, &vec![&env, rust.clone(), rust.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::DuplicateSkill)));
+
+    let mut too_many = vec![&env];
+    for tag in ["a", "b", "c", "d", "e", "f"] {
+        too_many.push_back(Symbol::new(&env, tag));
+    }
+    let res = client.try_set_skills(&expert, &expert, &too_many);
+    assert_eq!(res, Err(Ok(RegistryError::TooManySkills)));
+}
+
+#[test]
+fn test_suspension_expires_without_a_transaction() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+
+    let week = 7 * 24 * 60 * 60;
+    client.suspend_expert(&admin, &expert, &week, &42u32);
+
+    // The status change carries the moderator's reason
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let topic: Symbol = event.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "status_change"));
+    let data: crate::events::ExpertStatusChangedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data.old_status, ExpertStatus::Verified);
+    assert_eq!(data.new_status, ExpertStatus::Suspended);
+    assert_eq!(data.reason_code, 42);
+
+    assert!(!client.is_verified(&expert));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Suspended);
+
+    // Suspended experts can neither edit their profile nor be re-verified
+    let res = client.try_update_profile(
+        &expert,
+        &String::from_str(&env, "ipfs://y"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+    let res = client.try_add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyVerified)));
+
+    let page = client.list_experts(&0u64, &10u32, &Some(ExpertStatus::Suspended));
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().1.suspended_until, 1_000 + week);
+
+    // Still suspended on the last second, verified again right after
+    env.ledger().set_timestamp(1_000 + week - 1);
+    assert!(!client.is_verified(&expert));
+    env.ledger().set_timestamp(1_000 + week);
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
+}
+
+#[test]
+fn test_suspend_rejections_and_ban_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.init(&admin);
+
+    let res = client.try_suspend_expert(&admin, &expert, &100u64, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+
+    let res = client.try_suspend_expert(&stranger, &expert, &100u64, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    let res = client.try_suspend_expert(&admin, &expert, &0u64, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidDuration)));
+
+    // A permanent ban replaces a suspension
+    client.suspend_expert(&admin, &expert, &100u64, &1u32);
+    client.ban_expert(&admin, &expert);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+
+    let res = client.try_suspend_expert(&admin, &expert, &100u64, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+}
+
+#[test]
+fn test_appeal_accepted_reinstates_expert() {
+    use crate::types::AppealStatus;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://profile");
+    client.init(&admin);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    client.set_skills(&expert, &expert, &vec![&env, Symbol::new(&env, "rust")]);
+
+    env.ledger().set_timestamp(100);
+    client.ban_expert(&admin, &expert);
+
+    env.ledger().set_timestamp(200);
+    let evidence = String::from_str(&env, "ipfs://evidence");
+    client.file_appeal(&expert, &evidence);
+
+    let appeal = client.get_appeal(&expert);
+    assert_eq!(appeal.status, AppealStatus::Pending);
+    assert_eq!(appeal.evidence_uri, evidence);
+    assert_eq!(appeal.banned_at, 100);
+    assert_eq!(appeal.filed_at, 200);
+
+    // Only one appeal per ban
+    let res = client.try_file_appeal(&expert, &evidence);
+    assert_eq!(res, Err(Ok(RegistryError::AppealAlreadyFiled)));
+
+    client.resolve_appeal(&admin, &expert, &true);
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "appeal_resolved"));
+
+    let appeal = client.get_appeal(&expert);
+    assert_eq!(appeal.status, AppealStatus::Accepted);
+    assert_eq!(appeal.resolved_by, Some(admin.clone()));
+
+    // Reinstated with profile, tags and token intact
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.balance(&expert), 1);
+    let page = client.get_experts_by_skill(&Symbol::new(&env, "rust"), &0u64, &10u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().1.data_uri, uri);
+    assert_eq!(client.get_total_experts(), 1);
+
+    let res = client.try_resolve_appeal(&admin, &expert, &false);
+    assert_eq!(res, Err(Ok(RegistryError::AppealAlreadyResolved)));
+
+    // A later ban can be appealed again
+    env.ledger().set_timestamp(300);
+    client.ban_expert(&admin, &expert);
+    client.file_appeal(&expert, &evidence);
+    assert_eq!(client.get_appeal(&expert).banned_at, 300);
+}
+
+#[test]
+fn test_appeal_rejected_and_new_ban_allows_new_appeal() {
+    use crate::types::AppealStatus;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://profile");
+    let evidence = String::from_str(&env, "ipfs://evidence");
+    client.init(&admin);
+
+    // Only banned experts can appeal
+    let res = client.try_file_appeal(&expert, &evidence);
+    assert_eq!(res, Err(Ok(RegistryError::NotBanned)));
+    let res = client.try_get_appeal(&expert);
+    assert_eq!(res, Err(Ok(RegistryError::AppealNotFound)));
+
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    env.ledger().set_timestamp(100);
+    client.ban_expert(&admin, &expert);
+    client.file_appeal(&expert, &evidence);
+
+    let res = client.try_resolve_appeal(&stranger, &expert, &true);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    client.resolve_appeal(&admin, &expert, &false);
+    let appeal = client.get_appeal(&expert);
+    assert_eq!(appeal.status, AppealStatus::Rejected);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+
+    // The rejected ban stays closed to further appeals
+    let res = client.try_file_appeal(&expert, &evidence);
+    assert_eq!(res, Err(Ok(RegistryError::AppealAlreadyFiled)));
+
+    // Nor can the expert be verified around it
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyBanned)));
+}
+
+#[test]
+fn test_appeal_restores_status_held_before_ban() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    let evidence = String::from_str(&env, "ipfs://evidence");
+    client.init(&admin);
+
+    // A never-verified address is not verified by ban -> appeal -> accept
+    client.ban_expert(&admin, &outsider);
+    client.file_appeal(&outsider, &evidence);
+    client.resolve_appeal(&admin, &outsider, &true);
+    assert_eq!(client.get_status(&outsider), ExpertStatus::Unverified);
+    assert_eq!(client.get_total_experts(), 0);
+    assert_eq!(client.balance(&outsider), 0);
+
+    // A verified expert must meet the bond requirement again after the slash
+    let token = setup_bond(&env, &client, &admin, &treasury);
+    fund(&env, &token, &expert, 5_000);
+    client.deposit_bond(&expert, &1_000);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    client.ban_expert(&admin, &expert);
+    client.file_appeal(&expert, &evidence);
+
+    let res = client.try_resolve_appeal(&admin, &expert, &true);
+    assert_eq!(res, Err(Ok(RegistryError::BondRequired)));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+
+    client.deposit_bond(&expert, &250);
+    client.resolve_appeal(&admin, &expert, &true);
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.balance(&expert), 1);
+}
+
+#[test]
+fn test_appeal_keeps_suspension_and_rejects_stale_appeals() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    let evidence = String::from_str(&env, "ipfs://evidence");
+    client.init(&admin);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+
+    // The suspension running at the time of the ban resumes on reinstatement
+    env.ledger().set_timestamp(100);
+    client.suspend_expert(&admin, &expert, &100_000u64, &1u32);
+    env.ledger().set_timestamp(200);
+    client.ban_expert(&admin, &expert);
+    client.file_appeal(&expert, &evidence);
+    client.resolve_appeal(&admin, &expert, &true);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Suspended);
+    env.ledger().set_timestamp(100_100);
+    assert!(client.is_verified(&expert));
+
+    // An appeal left over from an earlier ban cannot lift the current one
+    client.ban_expert(&admin, &expert);
+    client.file_appeal(&expert, &evidence);
+    env.as_contract(&contract_id, || {
+        let mut appeal = crate::storage::get_appeal(&env, &expert).unwrap();
+        appeal.banned_at = 200;
+        crate::storage::set_appeal(&env, &expert, &appeal);
+    });
+    let res = client.try_resolve_appeal(&admin, &expert, &true);
+    assert_eq!(res, Err(Ok(RegistryError::NotBanned)));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+
+    // It can still be closed out
+    client.resolve_appeal(&admin, &expert, &false);
+}
+
+#[test]
+fn test_application_queue_approve_and_reject() {
+    use crate::types::ApplicationStatus;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let e3 = Address::generate(&env);
+    client.init(&admin);
+
+    let uri1 = String::from_str(&env, "ipfs://one");
+    client.apply_for_verification(&e1, &uri1, &profile_hash(&env));
+    client.apply_for_verification(
+        &e2,
+        &String::from_str(&env, "ipfs://two"),
+        &profile_hash(&env),
+    );
+    client.apply_for_verification(
+        &e3,
+        &String::from_str(&env, "ipfs://three"),
+        &profile_hash(&env),
+    );
+    assert_eq!(env.auths()[0].0, e3);
+
+    let page = client.list_applications(&0u64, &2u32);
+    assert_eq!(page.applications.len(), 2);
+    assert_eq!(page.applications.get(0).unwrap().0, e1);
+    assert_eq!(page.applications.get(0).unwrap().1.data_uri, uri1);
+    assert_eq!(page.next_cursor, Some(2));
+
+    // Approval goes through the regular verification path
+    client.approve_application(&admin, &e1);
+    assert!(client.is_verified(&e1));
+    assert_eq!(client.get_total_experts(), 1);
+    assert_eq!(client.balance(&e1), 1);
+    let page = client.list_experts(&0u64, &10u32, &None);
+    assert_eq!(page.experts.get(0).unwrap().1.data_uri, uri1);
+    assert!(client.verify_profile_hash(&e1, &profile_hash(&env)));
+    assert_eq!(
+        client.get_application(&e1).status,
+        ApplicationStatus::Approved
+    );
+
+    client.reject_application(&admin, &e2, &7u32);
+    let app = client.get_application(&e2);
+    assert_eq!(app.status, ApplicationStatus::Rejected);
+    assert_eq!(app.reason_code, 7);
+    assert!(!client.is_verified(&e2));
+
+    // Only e3 is left in the queue
+    let page = client.list_applications(&0u64, &10u32);
+    assert_eq!(page.applications.len(), 1);
+    assert_eq!(page.applications.get(0).unwrap().0, e3);
+    assert_eq!(page.next_cursor, None);
+
+    // Verifying directly also clears the application
+    client.add_expert(
+        &admin,
+        &e3,
+        &String::from_str(&env, "ipfs://direct"),
+        &profile_hash(&env),
+    );
+    assert_eq!(
+        client.list_applications(&0u64, &10u32).applications.len(),
+        0
+    );
+
+    // A rejected applicant may apply again
+    client.apply_for_verification(
+        &e2,
+        &String::from_str(&env, "ipfs://retry"),
+        &profile_hash(&env),
+    );
+    assert_eq!(
+        client.get_application(&e2).status,
+        ApplicationStatus::Pending
+    );
+}
+
+#[test]
+fn test_application_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let banned = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+
+    let res = client.try_approve_application(&admin, &expert);
+    assert_eq!(res, Err(Ok(RegistryError::ApplicationNotFound)));
+
+    client.apply_for_verification(&expert, &uri, &profile_hash(&env));
+    let res = client.try_apply_for_verification(&expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::ApplicationPending)));
+
+    let res = client.try_approve_application(&stranger, &expert);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    let res = client.try_reject_application(&stranger, &expert, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    client.approve_application(&admin, &expert);
+    let res = client.try_apply_for_verification(&expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyVerified)));
+    let res = client.try_reject_application(&admin, &expert, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::ApplicationNotFound)));
+
+    client.ban_expert(&admin, &banned);
+    let res = client.try_apply_for_verification(&banned, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyBanned)));
+}
+
+fn setup_bond<'a>(
+    env: &'a Env,
+    client: &IdentityRegistryContractClient,
+    admin: &Address,
+    treasury: &Address,
+) -> soroban_sdk::token::Client<'a> {
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin);
+    client.set_bond_config(
+        admin,
+        &crate::types::BondConfig {
+            token: token.address(),
+            amount: 1_000,
+            treasury: treasury.clone(),
+            slash_bps: 2_500,
+            cooldown: 3_600,
+        },
+    );
+    soroban_sdk::token::Client::new(env, &token.address())
+}
+
+fn fund(env: &Env, token: &soroban_sdk::token::Client, to: &Address, amount: i128) {
+    soroban_sdk::token::StellarAssetClient::new(env, &token.address).mint(to, &amount);
+}
+
+#[test]
+fn test_bond_required_for_verification_and_slashed_on_ban() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+    let token = setup_bond(&env, &client, &admin, &treasury);
+    fund(&env, &token, &expert, 5_000);
+
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::BondRequired)));
+    let res = client.try_batch_add_experts(&admin, &vec![&env, expert.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::BondRequired)));
+
+    // A partial deposit is not enough; topping up is
+    client.deposit_bond(&expert, &400);
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::BondRequired)));
+    client.deposit_bond(&expert, &600);
+    assert_eq!(token.balance(&contract_id), 1_000);
+
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert!(client.is_verified(&expert));
+
+    // Banning slashes 25% to the treasury
+    client.ban_expert(&admin, &expert);
+    assert_eq!(token.balance(&treasury), 250);
+    assert_eq!(token.balance(&contract_id), 750);
+    let page = client.list_experts(&0u64, &1u32, &None);
+    assert_eq!(page.experts.get(0).unwrap().1.bond, 750);
+}
+
+#[test]
+fn test_withdraw_bond_cooldown_suspension_and_exit() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    let token = setup_bond(&env, &client, &admin, &treasury);
+    fund(&env, &token, &expert, 1_000);
+
+    let res = client.try_withdraw_bond(&expert);
+    assert_eq!(res, Err(Ok(RegistryError::NoBond)));
+
+    client.deposit_bond(&expert, &1_000);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+
+    let res = client.try_withdraw_bond(&expert);
+    assert_eq!(res, Err(Ok(RegistryError::BondCooldown)));
+
+    // Suspended experts cannot exit until the suspension ends
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.suspend_expert(&admin, &expert, &600u64, &1u32);
+    let res = client.try_withdraw_bond(&expert);
+    assert_eq!(res, Err(Ok(RegistryError::ExpertSuspended)));
+
+    env.ledger().set_timestamp(1_000 + 3_600 + 600);
+    assert_eq!(client.withdraw_bond(&expert), 1_000);
+    assert_eq!(token.balance(&expert), 1_000);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    // Leaving gives up verification
+    assert_eq!(client.get_status(&expert), ExpertStatus::Unverified);
+    assert_eq!(client.balance(&expert), 0);
+
+    // So does leaving after the verification lapsed
+    let lapsed = Address::generate(&env);
+    fund(&env, &token, &lapsed, 1_000);
+    client.deposit_bond(&lapsed, &1_000);
+    client.set_verification_period(&admin, &100u64);
+    client.add_expert(
+        &admin,
+        &lapsed,
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+    env.ledger().set_timestamp(1_000 + 3_600 + 600 + 3_600);
+    assert_eq!(client.get_status(&lapsed), ExpertStatus::Expired);
+    assert_eq!(client.withdraw_bond(&lapsed), 1_000);
+    assert_eq!(client.get_status(&lapsed), ExpertStatus::Unverified);
+    assert_eq!(client.balance(&lapsed), 0);
+}
+
+#[test]
+fn test_bond_config_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+
+    let res = client.try_deposit_bond(&expert, &100);
+    assert_eq!(res, Err(Ok(RegistryError::BondNotConfigured)));
+
+    let token = setup_bond(&env, &client, &admin, &treasury);
+    let mut config = client.get_bond_config();
+
+    let res = client.try_set_bond_config(&stranger, &config);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    config.slash_bps = 10_001;
+    let res = client.try_set_bond_config(&admin, &config);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidBondConfig)));
+
+    let res = client.try_deposit_bond(&expert, &0);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidBondAmount)));
+
+    // The token cannot change while bonds are held
+    fund(&env, &token, &expert, 100);
+    client.deposit_bond(&expert, &100);
+    let mut config = client.get_bond_config();
+    config.token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let res = client.try_set_bond_config(&admin, &config);
+    assert_eq!(res, Err(Ok(RegistryError::BondsOutstanding)));
+
+    // Dropping the amount to zero makes the bond optional
+    let mut config = client.get_bond_config();
+    config.amount = 0;
+    client.set_bond_config(&admin, &config);
+    client.add_expert(
+        &admin,
+        &Address::generate(&env),
+        &String::from_str(&env, "ipfs://x"),
+        &profile_hash(&env),
+    );
+}
+
+#[test]
+fn test_verification_expires_and_renews() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let early = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+
+    // Verified before a period was configured: lapses a period after its last update
+    env.ledger().set_timestamp(500);
+    client.add_expert(&admin, &early, &uri, &profile_hash(&env));
+    env.ledger().set_timestamp(1_000);
+
+    client.set_verification_period(&admin, &10_000u64);
+    assert_eq!(client.get_verification_period(), 10_000);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+
+    // Profile edits do not push the expiry back
+    env.ledger().set_timestamp(10_000);
+    client.update_profile(&early, &uri, &profile_hash(&env));
+    env.ledger().set_timestamp(10_499);
+    assert!(client.is_verified(&early));
+    let page = client.list_expiring(&10_501u64, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, early);
+    env.ledger().set_timestamp(10_500);
+    assert_eq!(client.get_status(&early), ExpertStatus::Expired);
+
+    env.ledger().set_timestamp(10_999);
+    assert!(client.is_verified(&expert));
+    env.ledger().set_timestamp(11_000);
+    assert!(!client.is_verified(&expert));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Expired);
+
+    // Lapsed experts cannot edit their profile
+    let res = client.try_update_profile(&expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+
+    assert_eq!(client.renew_verification(&admin, &expert), 21_000);
+    assert!(client.is_verified(&expert));
+
+    // Without a period, verifications lacking an expiry date never lapse
+    client.set_verification_period(&admin, &0u64);
+    assert!(client.is_verified(&early));
+}
+
+#[test]
+fn test_list_expiring_and_renew_rejections() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(0);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let e3 = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+
+    let res = client.try_set_verification_period(&stranger, &100u64);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    client.set_verification_period(&admin, &100u64);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env)); // lapses at 100
+    env.ledger().set_timestamp(50);
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env)); // lapses at 150
+    client.add_expert(&admin, &e3, &uri, &profile_hash(&env));
+    client.ban_expert(&admin, &e3);
+
+    let page = client.list_expiring(&120u64, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, e1);
+    assert_eq!(page.experts.get(0).unwrap().1.verified_until, 100);
+
+    // Already lapsed records are still listed, flagged as Expired
+    env.ledger().set_timestamp(200);
+    let page = client.list_expiring(&200u64, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 2);
+    assert_eq!(page.experts.get(1).unwrap().1.status, ExpertStatus::Expired);
+
+    let res = client.try_renew_verification(&stranger, &e1);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    let res = client.try_renew_verification(&admin, &e3);
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+}
+
+#[test]
+fn test_attestations_gate_verification() {
+    use crate::types::AttestationPolicy;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(100);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let kyc = Address::generate(&env);
+    let university = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Attester, &kyc);
+    client.grant_role(&admin, &Role::Attester, &university);
+
+    let kyc_claim = Symbol::new(&env, "kyc");
+    let degree_claim = Symbol::new(&env, "degree");
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.set_attestation_policy(
+        &admin,
+        &AttestationPolicy {
+            claim_types: vec![&env, kyc_claim.clone(), degree_claim.clone()],
+            required: 2,
+        },
+    );
+
+    client.attest(&kyc, &expert, &kyc_claim, &hash, &0u64);
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::NotEnoughAttestations)));
+
+    // An expired attestation does not count
+    client.attest(&university, &expert, &degree_claim, &hash, &200u64);
+    env.ledger().set_timestamp(200);
+    let res = client.try_add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::NotEnoughAttestations)));
+
+    // Attesting again replaces the earlier attestation
+    client.attest(&university, &expert, &degree_claim, &hash, &0u64);
+    assert_eq!(client.get_attestations(&expert).len(), 2);
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    assert!(client.is_verified(&expert));
+
+    // Attestations from a former attester stop counting
+    let other = Address::generate(&env);
+    client.attest(&kyc, &other, &kyc_claim, &hash, &0u64);
+    client.attest(&university, &other, &degree_claim, &hash, &0u64);
+    client.revoke_role(&admin, &Role::Attester, &university);
+    let res = client.try_add_expert(&admin, &other, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::NotEnoughAttestations)));
+}
+
+#[test]
+fn test_attestation_revocation_and_rejections() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(100);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Attester, &attester);
+
+    let claim = Symbol::new(&env, "employer");
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    let res = client.try_attest(&stranger, &expert, &claim, &hash, &0u64);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    let res = client.try_attest(&attester, &expert, &claim, &hash, &100u64);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidExpiry)));
+
+    client.attest(&attester, &expert, &claim, &hash, &0u64);
+    let attestation = client.get_attestations(&expert).get(0).unwrap();
+    assert_eq!(attestation.attester, attester);
+    assert_eq!(attestation.claim_hash, hash);
+    assert_eq!(attestation.issued_at, 100);
+    assert!(!attestation.revoked);
+
+    // Only the attester or a moderator may revoke
+    let res = client.try_revoke_attestation(&stranger, &expert, &attester, &claim);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    client.revoke_attestation(&attester, &expert, &attester, &claim);
+    assert!(client.get_attestations(&expert).get(0).unwrap().revoked);
+
+    let res = client.try_revoke_attestation(&admin, &expert, &attester, &claim);
+    assert_eq!(res, Err(Ok(RegistryError::AttestationNotFound)));
+}
+
+fn install_council(
+    env: &Env,
+    client: &IdentityRegistryContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let s1 = Address::generate(env);
+    let s2 = Address::generate(env);
+    let s3 = Address::generate(env);
+    client.set_council(
+        admin,
+        &crate::types::Council {
+            signers: vec![env, s1.clone(), s2.clone(), s3.clone()],
+            threshold: 2,
+            proposal_ttl: 1_000,
+        },
+    );
+    (s1, s2, s3)
+}
+
+#[test]
+fn test_council_gates_sensitive_actions() {
+    use crate::types::CouncilAction;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let account = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    client.init(&admin);
+    let (s1, s2, _s3) = install_council(&env, &client, &admin);
+
+    // Single-signer paths are closed for sensitive actions
+    client.add_expert(&admin, &expert, &uri, &profile_hash(&env));
+    let res = client.try_ban_expert(&admin, &expert);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_batch_ban_experts(&admin, &vec![&env, expert.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_grant_role(&admin, &Role::Moderator, &account);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_revoke_role(&admin, &Role::Moderator, &account);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_upgrade(&BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_suspend_expert(&admin, &expert, &u64::MAX, &1u32);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_transfer_admin(&account);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+    let res = client.try_set_verification_period(&admin, &1u64);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+
+    // The verification period through a proposal
+    let id = client.propose(&s1, &CouncilAction::SetVerificationPeriod(5_000u64));
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s1, &id);
+    assert_eq!(client.get_verification_period(), 5_000);
+
+    // Suspend through a proposal
+    let id = client.propose(&s1, &CouncilAction::Suspend(expert.clone(), 100u64, 7u32));
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s1, &id);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Suspended);
+
+    // Ban through a 2-of-3 proposal
+    let id = client.propose(&s1, &CouncilAction::Ban(expert.clone()));
+    assert_eq!(env.auths()[0].0, s1);
+    let res = client.try_execute_proposal(&s1, &id);
+    assert_eq!(res, Err(Ok(RegistryError::ThresholdNotMet)));
+
+    client.approve_proposal(&s2, &id);
+    let res = client.try_approve_proposal(&s2, &id);
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyApproved)));
+
+    client.execute_proposal(&s2, &id);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+    assert!(client.get_proposal(&id).executed);
+    let res = client.try_execute_proposal(&s1, &id);
+    assert_eq!(res, Err(Ok(RegistryError::ProposalAlreadyExecuted)));
+
+    // Role changes too
+    let id = client.propose(
+        &s1,
+        &CouncilAction::GrantRole(Role::Verifier, account.clone()),
+    );
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s1, &id);
+    assert!(client.has_role(&Role::Verifier, &account));
+
+    // Low-risk actions stay single-signer
+    client.add_expert(
+        &account,
+        &Address::generate(&env),
+        &uri,
+        &profile_hash(&env),
+    );
+}
+
+#[test]
+fn test_council_gates_admin_transfer() {
+    use crate::types::CouncilAction;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let early = Address::generate(&env);
+    let successor = Address::generate(&env);
+    client.init(&admin);
+
+    // A transfer proposed before the council cannot be completed under it
+    client.transfer_admin(&early);
+    let (s1, s2, _s3) = install_council(&env, &client, &admin);
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(RegistryError::NoPendingAdmin)));
+
+    let id = client.propose(&s1, &CouncilAction::TransferAdmin(successor.clone()));
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s2, &id);
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, successor);
+    assert!(client.has_role(&Role::SuperAdmin, &successor));
+    assert!(!client.has_role(&Role::SuperAdmin, &admin));
+}
+
+#[test]
+fn test_council_membership_expiry_and_rotation() {
+    use crate::types::{Council, CouncilAction};
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(0);
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+
+    let bad = Council {
+        signers: vec![&env, admin.clone()],
+        threshold: 2,
+        proposal_ttl: 1_000,
+    };
+    let res = client.try_set_council(&admin, &bad);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidCouncil)));
+
+    let (s1, s2, s3) = install_council(&env, &client, &admin);
+
+    // The admin cannot swap the council out alone
+    let res = client.try_set_council(&admin, &bad);
+    assert_eq!(res, Err(Ok(RegistryError::CouncilRequired)));
+
+    let res = client.try_propose(&outsider, &CouncilAction::Ban(expert.clone()));
+    assert_eq!(res, Err(Ok(RegistryError::NotCouncilMember)));
+    let res = client.try_get_proposal(&99u64);
+    assert_eq!(res, Err(Ok(RegistryError::ProposalNotFound)));
+
+    // Proposals expire
+    let id = client.propose(&s1, &CouncilAction::Ban(expert.clone()));
+    env.ledger().set_timestamp(1_000);
+    let res = client.try_approve_proposal(&s2, &id);
+    assert_eq!(res, Err(Ok(RegistryError::ProposalExpired)));
+
+    // Rotating s1 out voids their pending approvals
+    let stale = client.propose(&s1, &CouncilAction::Ban(expert.clone()));
+    let rotate = client.propose(
+        &s2,
+        &CouncilAction::SetCouncil(Council {
+            signers: vec![&env, s2.clone(), s3.clone()],
+            threshold: 2,
+            proposal_ttl: 1_000,
+        }),
+    );
+    client.approve_proposal(&s3, &rotate);
+    client.execute_proposal(&s3, &rotate);
+    assert_eq!(client.get_council().unwrap().signers.len(), 2);
+
+    client.approve_proposal(&s2, &stale);
+    let res = client.try_execute_proposal(&s2, &stale);
+    assert_eq!(res, Err(Ok(RegistryError::ThresholdNotMet)));
+
+    // Disbanding restores single-signer administration
+    let disband = client.propose(
+        &s2,
+        &CouncilAction::SetCouncil(Council {
+            signers: vec![&env],
+            threshold: 0,
+            proposal_ttl: 0,
+        }),
+    );
+    client.approve_proposal(&s3, &disband);
+    client.execute_proposal(&s2, &disband);
+    assert_eq!(client.get_council(), None);
+    client.ban_expert(&admin, &expert);
+}
+
+#[test]
+fn test_council_upgrade_proposal() {
+    use crate::types::CouncilAction;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://up"),
+        &profile_hash(&env),
+    );
+    let (s1, s2, _s3) = install_council(&env, &client, &admin);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(REGISTRY_WASM);
+    let id = client.propose(&s1, &CouncilAction::Upgrade(wasm_hash));
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s1, &id);
+
+    assert!(client.is_verified(&expert));
+}
+
+#[test]
+fn test_status_history_records_each_transition() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Moderator, &moderator);
+
+    let page = client.get_status_history(&expert, &0u64, &10u32);
+    assert_eq!(page.entries.len(), 0);
+    assert_eq!(page.next_cursor, None);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://history"),
+        &profile_hash(&env),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.suspend_expert(&moderator, &expert, &100u64, &42u32);
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    client.ban_expert(&moderator, &expert);
+    client.file_appeal(&expert, &String::from_str(&env, "ipfs://evidence"));
+    client.resolve_appeal(&moderator, &expert, &true);
+
+    let page = client.get_status_history(&expert, &0u64, &10u32);
+    assert_eq!(page.entries.len(), 4);
+    assert_eq!(page.next_cursor, None);
+
+    let verified = page.entries.get(0).unwrap();
+    assert_eq!(verified.timestamp, 1_000);
+    assert_eq!(verified.old_status, ExpertStatus::Unverified);
+    assert_eq!(verified.new_status, ExpertStatus::Verified);
+    assert_eq!(verified.actor, admin);
+    assert_eq!(verified.reason_code, 0);
+
+    let suspended = page.entries.get(1).unwrap();
+    assert_eq!(suspended.timestamp, 2_000);
+    assert_eq!(suspended.new_status, ExpertStatus::Suspended);
+    assert_eq!(suspended.actor, moderator);
+    assert_eq!(suspended.reason_code, 42);
+
+    let banned = page.entries.get(2).unwrap();
+    assert_eq!(banned.old_status, ExpertStatus::Verified);
+    assert_eq!(banned.new_status, ExpertStatus::Banned);
+
+    let reinstated = page.entries.get(3).unwrap();
+    assert_eq!(reinstated.old_status, ExpertStatus::Banned);
+    assert_eq!(reinstated.new_status, ExpertStatus::Verified);
+    assert_eq!(reinstated.actor, moderator);
+
+    // Paging walks the same entries in order
+    let first = client.get_status_history(&expert, &0u64, &3u32);
+    assert_eq!(first.entries.len(), 3);
+    assert_eq!(first.next_cursor, Some(3));
+    let rest = client.get_status_history(&expert, &3u64, &3u32);
+    assert_eq!(rest.entries.len(), 1);
+    assert_eq!(rest.entries.get(0).unwrap(), reinstated);
+    assert_eq!(rest.next_cursor, None);
+}
+
+#[test]
+fn test_status_history_is_bounded() {
+    use crate::history::MAX_HISTORY;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://history"),
+        &profile_hash(&env),
+    );
+
+    // Each suspension is logged with its own reason code
+    for reason in 1..=MAX_HISTORY {
+        client.suspend_expert(&admin, &expert, &100u64, &reason);
+    }
+
+    env.as_contract(&contract_id, || {
+        let history = storage::get_status_history(&env, &expert);
+        assert_eq!(history.len(), MAX_HISTORY);
+        // The initial verification was dropped to make room
+        assert_eq!(history.get(0).unwrap().reason_code, 1);
+        assert_eq!(history.last().unwrap().reason_code, MAX_HISTORY);
+    });
+}
+
+#[test]
+fn test_batch_add_experts_with_uris_reports_each_item() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let e3 = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &e2,
+        &String::from_str(&env, "ipfs://existing"),
+        &profile_hash(&env),
+    );
+
+    let uri1 = String::from_str(&env, "ipfs://one");
+    let long_uri = String::from_str(
+        &env,
+        "ipfs://aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+    );
+    let entries = vec![
+        &env,
+        (e1.clone(), uri1.clone()),
+        (e2.clone(), String::from_str(&env, "ipfs://two")),
+        (e3.clone(), long_uri),
+    ];
+    let results = client.batch_add_experts_with_uris(&admin, &entries);
+
+    assert_eq!(results.len(), 3);
+    let r1 = results.get(0).unwrap();
+    assert_eq!(r1.expert, e1);
+    assert!(r1.success);
+    assert_eq!(r1.error_code, 0);
+    let r2 = results.get(1).unwrap();
+    assert!(!r2.success);
+    assert_eq!(r2.error_code, RegistryError::AlreadyVerified as u32);
+    let r3 = results.get(2).unwrap();
+    assert!(!r3.success);
+    assert_eq!(r3.error_code, RegistryError::UriTooLong as u32);
+
+    // Only the good item was written, with its own URI
+    assert!(client.is_verified(&e1));
+    assert!(!client.is_verified(&e3));
+    assert_eq!(client.get_total_experts(), 2);
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::get_expert_record(&env, &e1).data_uri, uri1);
+        assert_eq!(
+            storage::get_expert_record(&env, &e2).data_uri,
+            String::from_str(&env, "ipfs://existing")
+        );
+    });
+}
+
+#[test]
+fn test_batch_with_results_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.init(&admin);
+
+    let mut entries = vec![&env];
+    let mut experts = vec![&env];
+    for _ in 0..21 {
+        let expert = Address::generate(&env);
+        entries.push_back((expert.clone(), String::from_str(&env, "ipfs://x")));
+        experts.push_back(expert);
+    }
+    let res = client.try_batch_add_experts_with_uris(&admin, &entries);
+    assert_eq!(res, Err(Ok(RegistryError::ExpertVecMax)));
+    let res = client.try_batch_ban_experts_with_results(&admin, &experts);
+    assert_eq!(res, Err(Ok(RegistryError::ExpertVecMax)));
+
+    let one = vec![&env, experts.get(0).unwrap()];
+    let res = client.try_batch_ban_experts_with_results(&stranger, &one);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+}
+
+#[test]
+fn test_batch_ban_experts_with_results_reports_each_item() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    client.init(&admin);
+    client.batch_add_experts(&admin, &vec![&env, e1.clone(), e2.clone()]);
+    client.ban_expert(&admin, &e2);
+
+    let results =
+        client.batch_ban_experts_with_results(&admin, &vec![&env, e1.clone(), e2.clone()]);
+
+    assert_eq!(results.len(), 2);
+    assert!(results.get(0).unwrap().success);
+    let r2 = results.get(1).unwrap();
+    assert_eq!(r2.expert, e2);
+    assert!(!r2.success);
+    assert_eq!(r2.error_code, RegistryError::AlreadyBanned as u32);
+    assert_eq!(client.get_status(&e1), ExpertStatus::Banned);
+}
+
+#[test]
+fn test_uri_policy_enforced_on_write_paths() {
+    use crate::types::UriPolicy;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    client.init(&admin);
+
+    // Defaults keep the historical 64-byte limit with any scheme
+    let default = client.get_uri_policy();
+    assert_eq!(default.max_len, 64);
+    assert_eq!(default.allowed_schemes.len(), 0);
+    assert!(!default.require_uri);
+
+    let policy = UriPolicy {
+        max_len: 32,
+        allowed_schemes: vec![
+            &env,
+            String::from_str(&env, "ipfs://"),
+            String::from_str(&env, "ar://"),
+        ],
+        require_uri: true,
+    };
+    client.set_uri_policy(&admin, &policy);
+    assert_eq!(client.get_uri_policy(), policy);
+
+    let res = client.try_add_expert(
+        &admin,
+        &e1,
+        &String::from_str(&env, "https://example.com"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::UriSchemeNotAllowed)));
+    // A scheme must match as a prefix, not anywhere in the URI
+    let res = client.try_add_expert(
+        &admin,
+        &e1,
+        &String::from_str(&env, "x-ipfs://abc"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::UriSchemeNotAllowed)));
+    let res = client.try_add_expert(
+        &admin,
+        &e1,
+        &String::from_str(&env, "ipfs://aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::UriTooLong)));
+    let res = client.try_batch_add_experts(&admin, &vec![&env, e2.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::UriRequired)));
+    let res = client.try_apply_for_verification(
+        &e2,
+        &String::from_str(&env, "https://example.com"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::UriSchemeNotAllowed)));
+
+    client.add_expert(
+        &admin,
+        &e1,
+        &String::from_str(&env, "ar://profile"),
+        &profile_hash(&env),
+    );
+    assert!(client.is_verified(&e1));
+
+    let res = client.try_update_profile(
+        &e1,
+        &String::from_str(&env, "https://example.com"),
+        &profile_hash(&env),
+    );
+    assert_eq!(res, Err(Ok(RegistryError::UriSchemeNotAllowed)));
+    let res = client.try_update_profile(&e1, &String::from_str(&env, ""), &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::UriRequired)));
+    client.update_profile(
+        &e1,
+        &String::from_str(&env, "ipfs://updated"),
+        &profile_hash(&env),
+    );
+
+    // Per-item batches report the policy error instead of reverting
+    let results = client.batch_add_experts_with_uris(
+        &admin,
+        &vec![&env, (e2.clone(), String::from_str(&env, "https://x"))],
+    );
+    assert_eq!(
+        results.get(0).unwrap().error_code,
+        RegistryError::UriSchemeNotAllowed as u32
+    );
+}
+
+#[test]
+fn test_set_uri_policy_rejections() {
+    use crate::types::UriPolicy;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.init(&admin);
+
+    let valid = UriPolicy {
+        max_len: 64,
+        allowed_schemes: vec![&env, String::from_str(&env, "ipfs://")],
+        require_uri: false,
+    };
+    let res = client.try_set_uri_policy(&stranger, &valid);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    let mut zero_len = valid.clone();
+    zero_len.max_len = 0;
+    let res = client.try_set_uri_policy(&admin, &zero_len);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidUriPolicy)));
+
+    let mut too_long = valid.clone();
+    too_long.max_len = 257;
+    let res = client.try_set_uri_policy(&admin, &too_long);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidUriPolicy)));
+
+    let mut empty_scheme = valid.clone();
+    empty_scheme.allowed_schemes = vec![&env, String::from_str(&env, "")];
+    let res = client.try_set_uri_policy(&admin, &empty_scheme);
+    assert_eq!(res, Err(Ok(RegistryError::InvalidUriPolicy)));
+}
+
+#[test]
+fn test_pause_blocks_verification_and_profiles() {
+    use crate::types::PauseGroup;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://paused");
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env));
+
+    client.pause(&guardian, &PauseGroup::Verification);
+    assert!(client.is_paused(&PauseGroup::Verification));
+    assert!(!client.is_paused(&PauseGroup::Profiles));
+
+    let res = client.try_add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+    let res = client.try_batch_add_experts(&admin, &vec![&env, e2.clone()]);
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+    let res =
+        client.try_batch_add_experts_with_uris(&admin, &vec![&env, (e2.clone(), uri.clone())]);
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+    let res = client.try_apply_for_verification(&e2, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+
+    // Profiles are a separate group
+    client.update_profile(&e1, &uri, &profile_hash(&env));
+
+    client.pause(&guardian, &PauseGroup::Profiles);
+    let res = client.try_update_profile(&e1, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+    let res = client.try_set_skills(&e1, &e1, &vec![&env, Symbol::new(&env, "rust")]);
+    assert_eq!(res, Err(Ok(RegistryError::ContractPaused)));
+
+    // Moderation is never paused
+    client.ban_expert(&admin, &e1);
+    assert_eq!(client.get_status(&e1), ExpertStatus::Banned);
+
+    client.unpause(&guardian, &PauseGroup::Verification);
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let payload: crate::events::PauseEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(payload.group, PauseGroup::Verification);
+    assert_eq!(payload.by, guardian);
+
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    assert!(client.is_verified(&e2));
+}
+
+#[test]
+fn test_pause_requires_guardian() {
+    use crate::types::PauseGroup;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+
+    let res = client.try_pause(&verifier, &PauseGroup::Verification);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+
+    // The admin holds every role, Guardian included
+    client.pause(&admin, &PauseGroup::Verification);
+    let res = client.try_unpause(&verifier, &PauseGroup::Verification);
+    assert_eq!(res, Err(Ok(RegistryError::MissingRole)));
+    client.unpause(&admin, &PauseGroup::Verification);
+    assert!(!client.is_paused(&PauseGroup::Verification));
+}
+
+#[test]
+fn test_claim_and_resolve_handle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://handle");
+    client.init(&admin);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env));
+
+    client.claim_handle(&e1, &String::from_str(&env, "Alice_Smith"));
+    let payload: crate::events::HandleClaimedEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(payload.handle, String::from_str(&env, "alice_smith"));
+
+    // Lookups ignore case, and the record carries the reverse mapping
+    assert_eq!(
+        client.resolve_handle(&String::from_str(&env, "ALICE_smith")),
+        e1
+    );
+    assert_eq!(
+        client.get_handle(&e1),
+        Some(String::from_str(&env, "alice_smith"))
+    );
+    assert_eq!(client.get_handle(&e2), None);
+
+    let res = client.try_claim_handle(&e2, &String::from_str(&env, "alice_SMITH"));
+    assert_eq!(res, Err(Ok(RegistryError::HandleTaken)));
+
+    // Claiming again frees the previous handle
+    client.claim_handle(&e1, &String::from_str(&env, "alice"));
+    let res = client.try_resolve_handle(&String::from_str(&env, "alice_smith"));
+    assert_eq!(res, Err(Ok(RegistryError::HandleNotFound)));
+    client.claim_handle(&e2, &String::from_str(&env, "alice_smith"));
+    assert_eq!(
+        client.resolve_handle(&String::from_str(&env, "alice_smith")),
+        e2
+    );
+
+    let res = client.try_claim_handle(&stranger, &String::from_str(&env, "stranger"));
+    assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
+}
+
+#[test]
+fn test_claim_handle_rejects_invalid_handles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://handle"),
+        &profile_hash(&env),
+    );
+
+    for bad in [
+        "ab",
+        "has space",
+        "dash-name",
+        "emoji_\u{1f600}",
+        "a_handle_that_is_far_too_long_xyz",
+    ] {
+        let res = client.try_claim_handle(&expert, &String::from_str(&env, bad));
+        assert_eq!(res, Err(Ok(RegistryError::InvalidHandle)));
+    }
+    assert_eq!(client.get_handle(&expert), None);
+}
+
+#[test]
+fn test_ban_quarantines_handle() {
+    use crate::handles::QUARANTINE_PERIOD;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let e1 = Address::generate(&env);
+    let e2 = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://handle");
+    let handle = String::from_str(&env, "bob");
+    client.init(&admin);
+    client.add_expert(&admin, &e1, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &e2, &uri, &profile_hash(&env));
+    client.claim_handle(&e1, &handle);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.ban_expert(&admin, &e1);
+    assert_eq!(client.get_handle(&e1), None);
+    let res = client.try_resolve_handle(&handle);
+    assert_eq!(res, Err(Ok(RegistryError::HandleNotFound)));
+
+    let res = client.try_claim_handle(&e2, &handle);
+    assert_eq!(res, Err(Ok(RegistryError::HandleTaken)));
+
+    // A reinstated owner may take the handle back during quarantine
+    client.file_appeal(&e1, &String::from_str(&env, "ipfs://evidence"));
+    client.resolve_appeal(&admin, &e1, &true);
+    client.claim_handle(&e1, &handle);
+    assert_eq!(client.resolve_handle(&handle), e1);
+
+    client.ban_expert(&admin, &e1);
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + QUARANTINE_PERIOD);
+    client.claim_handle(&e2, &handle);
+    assert_eq!(client.resolve_handle(&handle), e2);
+}
+
+#[test]
+fn test_migrate_identity_moves_expert() {
+    use crate::error::IdentityError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let old = Address::generate(&env);
+    let new = Address::generate(&env);
+    let other = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://moving");
+    let tag = Symbol::new(&env, "rust");
+    client.init(&admin);
+    client.add_expert(&admin, &old, &uri, &profile_hash(&env));
+    client.add_expert(&admin, &other, &uri, &profile_hash(&env));
+    client.set_skills(&old, &old, &vec![&env, tag.clone()]);
+    client.claim_handle(&old, &String::from_str(&env, "mover"));
+    let token_id = client.token_of(&old);
+
+    let res = client.try_migrate_identity(&old, &other);
+    assert_eq!(res, Err(Ok(IdentityError::IdentityInUse)));
+    let res = client.try_migrate_identity(&new, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(IdentityError::ExpertNotFound)));
+
+    // Nor can it take over an address with its own attestations or application
+    let attester = Address::generate(&env);
+    let attested = Address::generate(&env);
+    client.grant_role(&admin, &Role::Attester, &attester);
+    client.attest(
+        &attester,
+        &attested,
+        &Symbol::new(&env, "kyc"),
+        &profile_hash(&env),
+        &0u64,
+    );
+    let res = client.try_migrate_identity(&old, &attested);
+    assert_eq!(res, Err(Ok(IdentityError::IdentityInUse)));
+    let applicant = Address::generate(&env);
+    client.apply_for_verification(&applicant, &uri, &profile_hash(&env));
+    let res = client.try_migrate_identity(&old, &applicant);
+    assert_eq!(res, Err(Ok(IdentityError::IdentityInUse)));
+
+    client.migrate_identity(&old, &new);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(signer, _)| *signer == old));
+    assert!(auths.iter().any(|(signer, _)| *signer == new));
+
+    // The old address only forwards
+    assert_eq!(client.get_status(&old), ExpertStatus::Migrated);
+    assert_eq!(client.get_migrated_to(&old), Some(new.clone()));
+    assert!(!client.is_verified(&old));
+    assert_eq!(client.balance(&old), 0);
+    assert_eq!(client.get_handle(&old), None);
+
+    // Everything else moved with the expert, directory slot included
+    assert!(client.is_verified(&new));
+    assert!(client.verify_profile_hash(&new, &profile_hash(&env)));
+    assert_eq!(client.get_total_experts(), 2);
+    assert_eq!(client.get_expert_by_index(&0u64), new);
+    assert_eq!(client.token_of(&new), token_id);
+    assert_eq!(client.resolve_handle(&String::from_str(&env, "mover")), new);
+    let page = client.get_experts_by_skill(&tag, &0u64, &10u32);
+    assert_eq!(page.experts.len(), 1);
+    assert_eq!(page.experts.get(0).unwrap().0, new);
+
+    let history = client.get_status_history(&old, &0u64, &10u32).entries;
+    assert_eq!(history.last().unwrap().new_status, ExpertStatus::Migrated);
+    assert_eq!(
+        client.get_status_history(&new, &0u64, &10u32).entries.len(),
+        1
+    );
+
+    // A migrated address cannot be migrated, verified or banned again
+    let res = client.try_migrate_identity(&old, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(IdentityError::AlreadyMigrated)));
+    let res = client.try_add_expert(&admin, &old, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyMigrated)));
+    let res = client.try_ban_expert(&admin, &old);
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyMigrated)));
+    let res = client.try_apply_for_verification(&old, &uri, &profile_hash(&env));
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_admin_recovery_waits_for_timelock() {
+    use crate::error::IdentityError;
+    use crate::identity::RECOVERY_TIMELOCK;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let old = Address::generate(&env);
+    let new = Address::generate(&env);
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+    client.add_expert(
+        &admin,
+        &old,
+        &String::from_str(&env, "ipfs://lost"),
+        &profile_hash(&env),
+    );
+
+    let res = client.try_request_recovery(&verifier, &old, &new);
+    assert_eq!(res, Err(Ok(IdentityError::MissingRole)));
+    let res = client.try_execute_recovery(&admin, &old);
+    assert_eq!(res, Err(Ok(IdentityError::RecoveryNotFound)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let executable_at = client.request_recovery(&admin, &old, &new);
+    assert_eq!(executable_at, 1_000 + RECOVERY_TIMELOCK);
+    assert_eq!(client.get_recovery(&old).unwrap().new_address, new);
+
+    // The old key can still stop it
+    client.cancel_recovery(&old, &old);
+    assert_eq!(client.get_recovery(&old), None);
+    let res = client.try_cancel_recovery(&old, &old);
+    assert_eq!(res, Err(Ok(IdentityError::RecoveryNotFound)));
+
+    client.request_recovery(&admin, &old, &new);
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + RECOVERY_TIMELOCK - 1);
+    let res = client.try_execute_recovery(&admin, &old);
+    assert_eq!(res, Err(Ok(IdentityError::RecoveryTimelocked)));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + RECOVERY_TIMELOCK);
+    client.execute_recovery(&admin, &old);
+    let payload: crate::events::IdentityMigratedEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(payload.new_address, new);
+    assert_eq!(payload.migrated_by, admin);
+
+    assert_eq!(client.get_status(&old), ExpertStatus::Migrated);
+    assert!(client.is_verified(&new));
+    assert_eq!(client.get_recovery(&old), None);
+}
+
+#[test]
+fn test_council_gates_admin_recovery() {
+    use crate::error::IdentityError;
+    use crate::identity::RECOVERY_TIMELOCK;
+    use crate::types::CouncilAction;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let old = Address::generate(&env);
+    let new = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &old,
+        &String::from_str(&env, "ipfs://lost"),
+        &profile_hash(&env),
+    );
+
+    // A request filed before the council still needs its approval to execute
+    client.request_recovery(&admin, &old, &attacker);
+    let (s1, s2, _s3) = install_council(&env, &client, &admin);
+    env.ledger().with_mut(|li| li.timestamp = RECOVERY_TIMELOCK);
+    let res = client.try_execute_recovery(&admin, &old);
+    assert_eq!(res, Err(Ok(IdentityError::CouncilRequired)));
+    client.cancel_recovery(&admin, &old);
+
+    let res = client.try_request_recovery(&admin, &old, &new);
+    assert_eq!(res, Err(Ok(IdentityError::CouncilRequired)));
+
+    let id = client.propose(&s1, &CouncilAction::Recover(old.clone(), new.clone()));
+    client.approve_proposal(&s2, &id);
+    client.execute_proposal(&s2, &id);
+
+    // The approval only covers the address the council agreed to
+    let res = client.try_request_recovery(&admin, &old, &attacker);
+    assert_eq!(res, Err(Ok(IdentityError::CouncilRequired)));
+
+    let executable_at = client.request_recovery(&admin, &old, &new);
+    env.ledger().with_mut(|li| li.timestamp = executable_at);
+    client.execute_recovery(&admin, &old);
+    assert_eq!(client.get_status(&old), ExpertStatus::Migrated);
+    assert!(client.is_verified(&new));
+}
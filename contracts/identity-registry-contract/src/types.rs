@@ -1,5 +1,5 @@
 use soroban_sdk::contracttype;
-use soroban_sdk::String;
+use soroban_sdk::{Address, BytesN, String, Symbol, Vec};
 
 // 1. Expert Status Enum
 #[contracttype]
@@ -9,13 +9,230 @@ pub enum ExpertStatus {
     Unverified = 0,
     Verified = 1,
     Banned = 2,
+    Suspended = 3, // Never stored: a Verified record with an active suspension
+    Expired = 4,   // Never stored: a Verified record whose verification lapsed
+    Migrated = 5,  // Moved to another address, see get_migrated_to
 }
 
-// 2. Expert Record Struct
+// 2. Registry Roles
+// The admin implicitly holds every role
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Role {
+    SuperAdmin = 0, // Grants and revokes roles
+    Verifier = 1,   // Adds experts
+    Moderator = 2,  // Bans experts
+    Attester = 3,   // Attests to expert claims
+    Guardian = 4,   // Pauses and unpauses entrypoint groups
+}
+
+// 3. Expert Record Struct
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExpertRecord {
     pub status: ExpertStatus,
     pub updated_at: u64, // Ledger timestamp of the last change
     pub data_uri: String,
+    pub data_hash: BytesN<32>,  // SHA-256 of the document at data_uri
+    pub skills: Vec<Symbol>,    // Skill tags, at most MAX_SKILLS
+    pub suspended_until: u64,   // Ledger timestamp the current suspension ends, 0 if none
+    pub bond: i128,             // Bond held by the registry for this expert
+    pub bonded_at: u64, // Ledger timestamp of the last deposit, starts the withdrawal cooldown
+    pub verified_at: u64, // Ledger timestamp of the last verification or renewal
+    pub verified_until: u64, // Ledger timestamp the verification lapses, 0 to lapse a period after verified_at
+    pub handle: Option<String>, // Claimed handle, lowercased
+}
+
+// 4. Directory Page
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpertPage {
+    pub experts: Vec<(Address, ExpertRecord)>,
+    pub next_cursor: Option<u64>, // Index to pass as `start` for the next page, None when done
+}
+
+// 5. Ban Appeals
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AppealStatus {
+    Pending = 0,
+    Accepted = 1, // Expert reinstated to Verified
+    Rejected = 2, // Ban upheld
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    pub evidence_uri: String,
+    pub status: AppealStatus,
+    pub banned_at: u64, // updated_at of the ban being appealed, one appeal per ban
+    pub filed_at: u64,
+    pub resolved_at: Option<u64>,
+    pub resolved_by: Option<Address>,
+}
+
+// 6. Verification Applications
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ApplicationStatus {
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Application {
+    pub data_uri: String,
+    pub data_hash: BytesN<32>,
+    pub status: ApplicationStatus,
+    pub applied_at: u64,
+    pub resolved_at: Option<u64>,
+    pub reason_code: u32, // Set on rejection, 0 otherwise
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationPage {
+    pub applications: Vec<(Address, Application)>,
+    pub next_cursor: Option<u64>, // Index to pass as `start` for the next page, None when done
+}
+
+// 7. Verification Bond
+// A bond is required for verification when `amount` is above zero
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address, // Receives slashed bonds
+    pub slash_bps: u32,    // Share of the bond slashed on a ban, in basis points
+    pub cooldown: u64,     // Seconds after the last deposit before the bond can be withdrawn
+}
+
+// 8. Attestations
+// One attestation per (attester, claim_type); attesting again replaces it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub attester: Address,
+    pub claim_type: Symbol,
+    pub claim_hash: BytesN<32>, // Hash of the off-chain claim document
+    pub issued_at: u64,
+    pub expires_at: u64, // 0 if it never expires
+    pub revoked: bool,
+}
+
+// Verification requires at least `required` live attestations whose type is in `claim_types`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationPolicy {
+    pub claim_types: Vec<Symbol>,
+    pub required: u32,
+}
+
+// 9. Council
+// When installed, bans, role changes and upgrades need `threshold` of `signers`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub proposal_ttl: u64, // Seconds a proposal stays open
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    Ban(Address),
+    BatchBan(Vec<Address>),
+    Suspend(Address, u64, u32), // Expert, duration in seconds, reason code
+    GrantRole(Role, Address),
+    RevokeRole(Role, Address),
+    Upgrade(BytesN<32>),
+    SetCouncil(Council),    // An empty council with a zero threshold disbands it
+    TransferAdmin(Address), // The proposed admin still has to accept
+    Recover(Address, Address), // Approves recovering the first address to the second
+    SetVerificationPeriod(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: CouncilAction,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+// 10. Status History
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChange {
+    pub timestamp: u64,
+    pub old_status: ExpertStatus,
+    pub new_status: ExpertStatus,
+    pub actor: Address,   // Caller that made the change
+    pub reason_code: u32, // Moderator-supplied reason, 0 when none was given
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusHistoryPage {
+    pub entries: Vec<StatusChange>,
+    pub next_cursor: Option<u64>, // Index to pass as `start` for the next page, None when done
+}
+
+// 11. Batch Results
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResult {
+    pub expert: Address,
+    pub success: bool,
+    pub error_code: u32, // RegistryError code of a skipped item, 0 on success
+}
+
+// 12. URI Policy
+// An empty scheme list allows any scheme
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UriPolicy {
+    pub max_len: u32,
+    pub allowed_schemes: Vec<String>, // Prefixes such as "ipfs://", "ar://" or "https://"
+    pub require_uri: bool,            // Reject empty URIs, including batch adds
+}
+
+// 13. Emergency Pause
+// Bans, appeals and bond withdrawals are never paused
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PauseGroup {
+    Verification = 0, // add_expert, batch adds and apply_for_verification
+    Profiles = 1,     // update_profile, set_skills and claim_handle
+}
+
+// 14. Handles
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandleClaim {
+    pub owner: Address,
+    pub quarantined_until: u64, // Set when the owner was banned, 0 while the handle is held
+}
+
+// 15. Identity Migration
+// Admin recovery of an expert whose key was lost
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    pub new_address: Address,
+    pub requested_by: Address,
+    pub requested_at: u64,
+    pub executable_at: u64, // Ledger timestamp the recovery may run from
 }
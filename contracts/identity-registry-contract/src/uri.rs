@@ -0,0 +1,77 @@
+use crate::error::RegistryError;
+use crate::storage;
+use crate::types::UriPolicy;
+use soroban_sdk::{Env, String, Vec};
+
+// URI policy
+//
+// Every URI the registry stores is checked against an admin-set policy. Until
+// one is set, URIs up to 64 bytes of any scheme are accepted and may be empty.
+
+/// Maximum length used while no policy is set
+pub const DEFAULT_MAX_LEN: u32 = 64;
+
+/// Upper bound for a policy's `max_len`, which also sizes the scratch buffer
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Maximum number of allowed schemes in a policy
+pub const MAX_SCHEMES: u32 = 10;
+
+/// The policy in force, falling back to the defaults
+pub fn policy(env: &Env) -> UriPolicy {
+    storage::get_uri_policy(env).unwrap_or(UriPolicy {
+        max_len: DEFAULT_MAX_LEN,
+        allowed_schemes: Vec::new(env),
+        require_uri: false,
+    })
+}
+
+/// Check a policy before storing it
+pub fn validate_policy(policy: &UriPolicy) -> Result<(), RegistryError> {
+    if policy.max_len == 0 || policy.max_len > MAX_URI_LEN {
+        return Err(RegistryError::InvalidUriPolicy);
+    }
+    if policy.allowed_schemes.len() > MAX_SCHEMES {
+        return Err(RegistryError::InvalidUriPolicy);
+    }
+    for scheme in policy.allowed_schemes.iter() {
+        if scheme.is_empty() || scheme.len() > policy.max_len {
+            return Err(RegistryError::InvalidUriPolicy);
+        }
+    }
+    Ok(())
+}
+
+/// Fail unless `uri` satisfies the policy in force
+pub fn validate(env: &Env, uri: &String) -> Result<(), RegistryError> {
+    let policy = policy(env);
+
+    if uri.is_empty() {
+        if policy.require_uri {
+            return Err(RegistryError::UriRequired);
+        }
+        return Ok(());
+    }
+    if uri.len() > policy.max_len {
+        return Err(RegistryError::UriTooLong);
+    }
+    if policy.allowed_schemes.is_empty() {
+        return Ok(());
+    }
+
+    let mut uri_buf = [0u8; MAX_URI_LEN as usize];
+    let uri_bytes = &mut uri_buf[..uri.len() as usize];
+    uri.copy_into_slice(uri_bytes);
+
+    // Schemes are matched as exact byte prefixes, e.g. "ipfs://"
+    let mut scheme_buf = [0u8; MAX_URI_LEN as usize];
+    for scheme in policy.allowed_schemes.iter() {
+        let scheme_bytes = &mut scheme_buf[..scheme.len() as usize];
+        scheme.copy_into_slice(scheme_bytes);
+        if uri_bytes.starts_with(scheme_bytes) {
+            return Ok(());
+        }
+    }
+
+    Err(RegistryError::UriSchemeNotAllowed)
+}
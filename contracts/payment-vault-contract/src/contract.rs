@@ -1,14 +1,18 @@
 use crate::error::VaultError;
 use crate::events;
+use crate::migration::{self, CONTRACT_VERSION};
+use crate::registry::IdentityRegistryClient;
 use crate::storage;
-use crate::types::{BookingRecord, BookingStatus};
-use soroban_sdk::{token, Address, Env};
+use crate::transitions;
+use crate::types::{BookingRecord, BookingStatus, FeeConfig, PauseGroup};
+use soroban_sdk::{token, Address, BytesN, Env};
 
 pub fn initialize_vault(
     env: &Env,
     admin: &Address,
     token: &Address,
     oracle: &Address,
+    registry: &Address,
 ) -> Result<(), VaultError> {
     // 1. Check if already initialized
     if storage::has_admin(env) {
@@ -19,10 +23,183 @@ pub fn initialize_vault(
     storage::set_admin(env, admin);
     storage::set_token(env, token);
     storage::set_oracle(env, oracle);
+    storage::set_registry(env, registry);
+    storage::set_version(env, CONTRACT_VERSION);
 
     Ok(())
 }
 
+/// Replace the contract code in place (Admin-only)
+/// Call `migrate` afterwards if the new code changes the storage layout
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+    events::contract_upgraded(env, &new_wasm_hash);
+
+    Ok(())
+}
+
+/// Rewrite bookings stored by an older version into the current layout (Admin-only)
+pub fn migrate(env: &Env, start: u64, limit: u32) -> Result<Option<u64>, VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    let from_version = storage::get_version(env);
+    if from_version >= CONTRACT_VERSION {
+        return Err(VaultError::AlreadyMigrated);
+    }
+
+    let (next_cursor, records) = migration::migrate_from_v1(env, start, limit);
+    if next_cursor.is_some() {
+        return Ok(next_cursor);
+    }
+
+    // The version only moves once every page has been rewritten
+    storage::set_version(env, CONTRACT_VERSION);
+    events::storage_migrated(env, from_version, CONTRACT_VERSION, records);
+
+    Ok(None)
+}
+
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    storage::set_pending_admin(env, new_admin);
+    events::admin_proposed(env, &admin, new_admin);
+
+    Ok(())
+}
+
+pub fn accept_admin(env: &Env) -> Result<(), VaultError> {
+    let old_admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    let new_admin = storage::get_pending_admin(env).ok_or(VaultError::NoPendingAdmin)?;
+    new_admin.require_auth();
+
+    storage::set_admin(env, &new_admin);
+    storage::remove_pending_admin(env);
+    events::admin_transferred(env, &old_admin, &new_admin);
+
+    Ok(())
+}
+
+pub fn set_oracle(env: &Env, oracle: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    let old_oracle = storage::get_oracle(env).ok_or(VaultError::NotInitialized)?;
+    storage::set_oracle(env, oracle);
+    events::oracle_updated(env, &old_oracle, oracle);
+
+    Ok(())
+}
+
+/// Version 1 vaults had no registry, so upgraded deployments must set one here
+pub fn set_registry(env: &Env, registry: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    let old_registry = storage::get_registry(env);
+    storage::set_registry(env, registry);
+    events::registry_updated(env, &old_registry, registry);
+
+    Ok(())
+}
+
+pub fn set_token(env: &Env, token: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    // Escrowed deposits must be paid out in the token they were made in
+    if storage::get_open_bookings(env) > 0 {
+        return Err(VaultError::BookingsStillOpen);
+    }
+
+    let old_token = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
+    storage::set_token(env, token);
+    events::token_updated(env, &old_token, token);
+
+    Ok(())
+}
+
+/// Basis points denominator (100%)
+const MAX_FEE_BPS: u32 = 10_000;
+
+pub fn set_fee_config(env: &Env, fee_bps: u32, treasury: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(VaultError::InvalidFeeBps);
+    }
+
+    storage::set_fee_bps(env, fee_bps);
+    storage::set_treasury(env, treasury);
+    events::fee_config_updated(env, fee_bps, treasury);
+
+    Ok(())
+}
+
+pub fn get_fee_config(env: &Env) -> FeeConfig {
+    FeeConfig {
+        fee_bps: storage::get_fee_bps(env),
+        treasury: storage::get_treasury(env),
+    }
+}
+
+/// Platform fee owed on a cumulative gross payout, rounded down in the expert's favour.
+/// Fees are always computed on the running total so that streaming the payout in
+/// several withdrawals charges exactly the same fee as paying it in one go.
+fn fee_on(gross: i128, fee_bps: u32) -> i128 {
+    gross * (fee_bps as i128) / (MAX_FEE_BPS as i128)
+}
+
+/// Pay `amount` of the expert's gross earnings, on top of `already_paid`,
+/// splitting it between the expert and the treasury. Returns the fee portion.
+fn pay_expert(
+    env: &Env,
+    token_client: &token::Client,
+    booking: &BookingRecord,
+    already_paid: i128,
+    amount: i128,
+) -> i128 {
+    let fee =
+        fee_on(already_paid + amount, booking.fee_bps) - fee_on(already_paid, booking.fee_bps);
+    let contract_address = env.current_contract_address();
+
+    if fee > 0 {
+        // A fee is only ever snapshotted when a treasury has been configured
+        let treasury = storage::get_treasury(env).unwrap();
+        token_client.transfer(&contract_address, &treasury, &fee);
+    }
+    if amount - fee > 0 {
+        token_client.transfer(&contract_address, &booking.expert, &(amount - fee));
+    }
+
+    fee
+}
+
+/// Move a booking into a terminal status and release it from the open count
+fn close_booking(env: &Env, booking_id: u64, status: BookingStatus) {
+    storage::update_booking_status(env, booking_id, status);
+    storage::set_open_bookings(env, storage::get_open_bookings(env).saturating_sub(1));
+}
+
+/// Ask the Identity Registry whether the expert is currently verified
+fn require_verified_expert(env: &Env, expert: &Address) -> Result<(), VaultError> {
+    let registry = IdentityRegistryClient::new(
+        env,
+        &storage::get_registry(env).ok_or(VaultError::NotInitialized)?,
+    );
+    if !registry.is_verified(expert) {
+        return Err(VaultError::ExpertNotVerified);
+    }
+    Ok(())
+}
+
 pub fn set_my_rate(env: &Env, expert: &Address, rate_per_second: i128) -> Result<(), VaultError> {
     expert.require_auth();
 
@@ -30,6 +207,8 @@ pub fn set_my_rate(env: &Env, expert: &Address, rate_per_second: i128) -> Result
         return Err(VaultError::InvalidAmount);
     }
 
+    require_verified_expert(env, expert)?;
+
     storage::set_expert_rate(env, expert, rate_per_second);
     events::expert_rate_updated(env, expert, rate_per_second);
 
@@ -42,9 +221,14 @@ pub fn book_session(
     expert: &Address,
     max_duration: u64,
 ) -> Result<u64, VaultError> {
+    ensure_not_paused(env, PauseGroup::Bookings)?;
+
     // Require authorization from the user creating the booking
     user.require_auth();
 
+    // Only experts verified in the Identity Registry can be booked
+    require_verified_expert(env, expert)?;
+
     // Fetch the expert's rate
     let rate_per_second =
         storage::get_expert_rate(env, expert).ok_or(VaultError::ExpertRateNotSet)?;
@@ -62,7 +246,7 @@ pub fn book_session(
     }
 
     // Get the token contract
-    let token_address = storage::get_token(env);
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
     let token_client = token::Client::new(env, &token_address);
 
     // Transfer tokens from user to this contract
@@ -80,10 +264,17 @@ pub fn book_session(
         total_deposit,
         status: BookingStatus::Pending,
         created_at: env.ledger().timestamp(),
+        accepted_at: None,
+        started_at: None,
+        withdrawn: 0,
+        fee_bps: storage::get_fee_bps(env),
+        finalized_at: None,
+        final_pay: 0,
     };
 
     // Save booking
     storage::save_booking(env, &booking);
+    storage::set_open_bookings(env, storage::get_open_bookings(env) + 1);
 
     // Add booking to user and expert lists
     storage::add_booking_to_user_list(env, user, booking_id);
@@ -95,25 +286,132 @@ pub fn book_session(
     Ok(booking_id)
 }
 
+pub fn accept_session(env: &Env, expert: &Address, booking_id: u64) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
+    ensure_not_paused(env, PauseGroup::Bookings)?;
+
+    // 1. Require expert authorization
+    expert.require_auth();
+
+    // 2. Get booking and verify it exists
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Verify the caller is the expert in the booking
+    if booking.expert != *expert {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    // 4. Validate the move to Accepted
+    transitions::ensure_transition(booking.status, BookingStatus::Accepted)?;
+
+    // 5. Record acceptance
+    booking.status = BookingStatus::Accepted;
+    booking.accepted_at = Some(env.ledger().timestamp());
+    storage::save_booking(env, &booking);
+
+    // 6. Emit event
+    events::session_accepted(env, booking_id, expert);
+
+    Ok(())
+}
+
+pub fn start_session(env: &Env, booking_id: u64) -> Result<(), VaultError> {
+    ensure_not_paused(env, PauseGroup::Bookings)?;
+
+    // 1. Require Oracle authorization
+    let oracle = storage::get_oracle(env).ok_or(VaultError::NotInitialized)?;
+    oracle.require_auth();
+
+    // 2. Get booking and verify it exists
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Validate the move to InProgress
+    if booking.status == BookingStatus::InProgress {
+        return Err(VaultError::SessionAlreadyStarted);
+    }
+    transitions::ensure_transition(booking.status, BookingStatus::InProgress)?;
+
+    // 4. Record the start time
+    let started_at = env.ledger().timestamp();
+    booking.status = BookingStatus::InProgress;
+    booking.started_at = Some(started_at);
+    storage::save_booking(env, &booking);
+
+    // 5. Emit event
+    events::session_started(env, booking_id, started_at);
+
+    Ok(())
+}
+
+/// Amount earned by the expert so far: rate * elapsed seconds, capped at the deposit
+fn accrued_amount(env: &Env, booking: &BookingRecord, started_at: u64) -> i128 {
+    let elapsed = env.ledger().timestamp().saturating_sub(started_at);
+    let accrued = booking.rate_per_second.saturating_mul(elapsed as i128);
+    accrued.min(booking.total_deposit)
+}
+
+pub fn withdraw_accrued(env: &Env, booking_id: u64) -> Result<i128, VaultError> {
+    ensure_initialized(env)?;
+
+    // 1. Get booking and verify it exists
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 2. Require expert authorization
+    booking.expert.require_auth();
+
+    // 3. Verify the session is streaming
+    match booking.status {
+        BookingStatus::InProgress => {}
+        BookingStatus::Pending | BookingStatus::Accepted => {
+            return Err(VaultError::SessionNotStarted)
+        }
+        BookingStatus::Disputed => return Err(VaultError::BookingDisputed),
+        _ => return Err(VaultError::BookingNotPending),
+    }
+    let started_at = booking.started_at.ok_or(VaultError::SessionNotStarted)?;
+
+    // 4. Calculate what has accrued but not yet been withdrawn
+    let amount = accrued_amount(env, &booking, started_at) - booking.withdrawn;
+    if amount <= 0 {
+        return Err(VaultError::NothingToWithdraw);
+    }
+
+    // 5. Record the withdrawal before transferring
+    let already_paid = booking.withdrawn;
+    booking.withdrawn += amount;
+    storage::save_booking(env, &booking);
+
+    // 6. Pay expert, minus the platform fee
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
+    let token_client = token::Client::new(env, &token_address);
+    let fee = pay_expert(env, &token_client, &booking, already_paid, amount);
+
+    // 7. Emit event
+    events::accrued_withdrawn(env, booking_id, &booking.expert, amount - fee, fee);
+
+    Ok(amount - fee)
+}
+
 pub fn finalize_session(
     env: &Env,
     booking_id: u64,
     actual_duration: u64,
 ) -> Result<(), VaultError> {
+    ensure_not_paused(env, PauseGroup::Finalization)?;
+
     // 1. Require Oracle authorization
-    let oracle = storage::get_oracle(env);
+    let oracle = storage::get_oracle(env).ok_or(VaultError::NotInitialized)?;
     oracle.require_auth();
 
     // 2. Get booking and verify it exists
-    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
 
-    // 3. Verify booking is in Pending status
-    if booking.status != BookingStatus::Pending {
-        return Err(VaultError::BookingNotPending);
-    }
+    // 3. Validate the move to Finalized (session must be in progress)
+    transitions::ensure_transition(booking.status, BookingStatus::Finalized)?;
 
     // 4. Calculate payments
-    let expert_pay = booking.rate_per_second * (actual_duration as i128);
+    // Amounts already streamed to the expert are final, so they act as a floor
+    let expert_pay = (booking.rate_per_second * (actual_duration as i128)).max(booking.withdrawn);
     let refund = booking.total_deposit - expert_pay;
 
     // Ensure calculations are valid
@@ -121,35 +419,70 @@ pub fn finalize_session(
         return Err(VaultError::InvalidAmount);
     }
 
+    // 5. Hold the escrow until the dispute window closes
+    booking.status = BookingStatus::Finalized;
+    booking.finalized_at = Some(env.ledger().timestamp());
+    booking.final_pay = expert_pay;
+    storage::save_booking(env, &booking);
+
+    // 6. Emit SessionFinalized event
+    let total_fee = fee_on(expert_pay, booking.fee_bps);
+    events::session_finalized(env, booking_id, actual_duration, expert_pay, total_fee);
+
+    Ok(())
+}
+
+/// Pay out a finalized session once nobody disputed it in time (anyone)
+pub fn settle_session(env: &Env, booking_id: u64) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
+    ensure_not_paused(env, PauseGroup::Finalization)?;
+
+    // 1. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 2. Validate the move to Complete (session must be finalized)
+    transitions::ensure_transition(booking.status, BookingStatus::Complete)?;
+
+    // 3. Check the dispute window has closed
+    if env.ledger().timestamp() <= transitions::settleable_after(&booking) {
+        return Err(VaultError::DisputeWindowOpen);
+    }
+
+    // 4. Calculate payments from the duration the Oracle reported
+    let refund = booking.total_deposit - booking.final_pay;
+    let remaining_pay = booking.final_pay - booking.withdrawn;
+
     // 5. Get token contract
-    let token_address = storage::get_token(env);
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
     let token_client = token::Client::new(env, &token_address);
     let contract_address = env.current_contract_address();
 
     // 6. Execute transfers
-    // Pay expert
-    if expert_pay > 0 {
-        token_client.transfer(&contract_address, &booking.expert, &expert_pay);
-    }
-
-    // Refund user
+    // Pay expert whatever has not been streamed yet, minus the platform fee
+    pay_expert(
+        env,
+        &token_client,
+        &booking,
+        booking.withdrawn,
+        remaining_pay,
+    );
+
+    // Refund user (never subject to fees)
     if refund > 0 {
         token_client.transfer(&contract_address, &booking.user, &refund);
     }
 
     // 7. Update booking status to Complete
-    storage::update_booking_status(env, booking_id, BookingStatus::Complete);
+    close_booking(env, booking_id, BookingStatus::Complete);
 
-    // 8. Emit SessionFinalized event
-    events::session_finalized(env, booking_id, actual_duration, expert_pay);
+    // 8. Emit event
+    events::session_settled(env, booking_id, booking.final_pay, refund);
 
     Ok(())
 }
 
-/// 24 hours in seconds
-const RECLAIM_TIMEOUT: u64 = 86400;
-
 pub fn reclaim_stale_session(env: &Env, user: &Address, booking_id: u64) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
     // 1. Require user authorization
     user.require_auth();
 
@@ -161,33 +494,35 @@ pub fn reclaim_stale_session(env: &Env, user: &Address, booking_id: u64) -> Resu
         return Err(VaultError::NotAuthorized);
     }
 
-    // 4. Verify booking is in Pending status
-    if booking.status != BookingStatus::Pending {
-        return Err(VaultError::BookingNotPending);
-    }
+    // 4. Validate the move to Reclaimed
+    transitions::ensure_transition(booking.status, BookingStatus::Reclaimed)?;
 
-    // 5. Check if 24 hours have passed since booking creation
+    // 5. Check that the timeout for the current status has passed
     let current_time = env.ledger().timestamp();
-    if current_time <= booking.created_at + RECLAIM_TIMEOUT {
+    if current_time <= transitions::reclaimable_after(&booking) {
         return Err(VaultError::ReclaimTooEarly);
     }
 
-    // 6. Transfer total_deposit back to user
-    let token_address = storage::get_token(env);
+    // 6. Transfer whatever was not streamed to the expert back to user
+    let refund = booking.total_deposit - booking.withdrawn;
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
     let token_client = token::Client::new(env, &token_address);
     let contract_address = env.current_contract_address();
-    token_client.transfer(&contract_address, &booking.user, &booking.total_deposit);
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
 
     // 7. Update booking status to Reclaimed
-    storage::update_booking_status(env, booking_id, BookingStatus::Reclaimed);
+    close_booking(env, booking_id, BookingStatus::Reclaimed);
 
     // 8. Emit event
-    events::session_reclaimed(env, booking_id, booking.total_deposit);
+    events::session_reclaimed(env, booking_id, refund);
 
     Ok(())
 }
 
 pub fn reject_session(env: &Env, expert: &Address, booking_id: u64) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
     // 1. Require expert authorization
     expert.require_auth();
 
@@ -199,22 +534,252 @@ pub fn reject_session(env: &Env, expert: &Address, booking_id: u64) -> Result<()
         return Err(VaultError::NotAuthorized);
     }
 
-    // 4. Verify booking is in Pending status
-    if booking.status != BookingStatus::Pending {
-        return Err(VaultError::BookingNotPending);
-    }
+    // 4. Validate the move to Rejected (only before the session starts)
+    transitions::ensure_transition(booking.status, BookingStatus::Rejected)?;
 
-    // 5. Transfer total_deposit back to user
-    let token_address = storage::get_token(env);
+    // 5. Transfer whatever was not streamed to the expert back to user
+    let refund = booking.total_deposit - booking.withdrawn;
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
     let token_client = token::Client::new(env, &token_address);
     let contract_address = env.current_contract_address();
-    token_client.transfer(&contract_address, &booking.user, &booking.total_deposit);
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
 
     // 6. Update booking status to Rejected
-    storage::update_booking_status(env, booking_id, BookingStatus::Rejected);
+    close_booking(env, booking_id, BookingStatus::Rejected);
 
     // 7. Emit event
     events::session_rejected(env, booking_id, "Expert declined session");
 
     Ok(())
 }
+
+pub fn set_arbitrator(env: &Env, arbitrator: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    storage::set_arbitrator(env, arbitrator);
+    events::arbitrator_updated(env, arbitrator);
+
+    Ok(())
+}
+
+/// Appoint the guardian that may pause and unpause alongside the admin (Admin-only)
+pub fn set_guardian(env: &Env, guardian: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+
+    storage::set_guardian(env, guardian);
+    events::guardian_updated(env, guardian);
+
+    Ok(())
+}
+
+/// Stop a group of entrypoints (Admin or Guardian)
+pub fn pause(env: &Env, caller: &Address, group: PauseGroup) -> Result<(), VaultError> {
+    require_pauser(env, caller)?;
+
+    storage::set_paused(env, group, true);
+    events::paused(env, group, caller);
+
+    Ok(())
+}
+
+/// Resume a paused group of entrypoints (Admin or Guardian)
+pub fn unpause(env: &Env, caller: &Address, group: PauseGroup) -> Result<(), VaultError> {
+    require_pauser(env, caller)?;
+
+    storage::set_paused(env, group, false);
+    events::unpaused(env, group, caller);
+
+    Ok(())
+}
+
+fn require_pauser(env: &Env, caller: &Address) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    caller.require_auth();
+
+    if *caller != admin && storage::get_guardian(env).as_ref() != Some(caller) {
+        return Err(VaultError::NotAuthorized);
+    }
+    Ok(())
+}
+
+/// Fail before `init` has run
+fn ensure_initialized(env: &Env) -> Result<(), VaultError> {
+    if !storage::has_admin(env) {
+        return Err(VaultError::NotInitialized);
+    }
+    Ok(())
+}
+
+/// Fail while the group is paused
+fn ensure_not_paused(env: &Env, group: PauseGroup) -> Result<(), VaultError> {
+    if storage::is_paused(env, group) {
+        return Err(VaultError::ContractPaused);
+    }
+    Ok(())
+}
+
+pub fn open_dispute(env: &Env, caller: &Address, booking_id: u64) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
+
+    // A dispute freezes the escrow until the arbitrator resolves it
+    if storage::get_arbitrator(env).is_none() {
+        return Err(VaultError::ArbitratorNotSet);
+    }
+    // 1. Require caller authorization
+    caller.require_auth();
+
+    // 2. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Verify the caller is a party to the booking
+    if booking.user != *caller && booking.expert != *caller {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    // 4. Validate the move to Disputed
+    transitions::ensure_transition(booking.status, BookingStatus::Disputed)?;
+
+    // 5. Check the dispute window is still open
+    if !transitions::dispute_window_open(&booking, env.ledger().timestamp()) {
+        return Err(VaultError::DisputeWindowClosed);
+    }
+
+    // 6. Freeze the booking
+    storage::update_booking_status(env, booking_id, BookingStatus::Disputed);
+
+    // 7. Emit event
+    events::dispute_opened(env, booking_id, caller);
+
+    Ok(())
+}
+
+pub fn resolve_dispute(
+    env: &Env,
+    booking_id: u64,
+    expert_amount: i128,
+    user_amount: i128,
+) -> Result<(), VaultError> {
+    ensure_initialized(env)?;
+    ensure_not_paused(env, PauseGroup::Finalization)?;
+
+    // 1. Require Arbitrator authorization
+    let arbitrator = storage::get_arbitrator(env).ok_or(VaultError::ArbitratorNotSet)?;
+    arbitrator.require_auth();
+
+    // 2. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Validate the move to Resolved
+    transitions::ensure_transition(booking.status, BookingStatus::Resolved)?;
+
+    // 4. The split must account for exactly what is still in escrow
+    let escrowed = booking.total_deposit - booking.withdrawn;
+    if expert_amount < 0 || user_amount < 0 || expert_amount + user_amount != escrowed {
+        return Err(VaultError::InvalidAmount);
+    }
+
+    // 5. Execute transfers
+    let token_address = storage::get_token(env).ok_or(VaultError::NotInitialized)?;
+    let token_client = token::Client::new(env, &token_address);
+    let contract_address = env.current_contract_address();
+
+    // Expert share is earnings, so the platform fee applies
+    pay_expert(
+        env,
+        &token_client,
+        &booking,
+        booking.withdrawn,
+        expert_amount,
+    );
+
+    // User share is a refund (never subject to fees)
+    if user_amount > 0 {
+        token_client.transfer(&contract_address, &booking.user, &user_amount);
+    }
+
+    // 6. Update booking status to Resolved
+    close_booking(env, booking_id, BookingStatus::Resolved);
+
+    // 7. Emit event
+    events::dispute_resolved(env, booking_id, expert_amount, user_amount);
+
+    Ok(())
+}
+
+/// Most bookings a single `migrate_expert` call walks
+pub const EXPERT_MIGRATION_PAGE_SIZE: u32 = 25;
+
+/// Move one page of an expert's bookings to the address the Identity Registry
+/// says they migrated to. Anyone may call this; the registry is the authority.
+///
+/// Only bookings still holding escrow are re-pointed, since finished ones pay
+/// nobody again. Only a page starting at or before the stored cursor moves it
+/// forward, and re-running a page is harmless. The final page moves the rate
+/// and the booking list. Returns the position to continue from, or None once
+/// the expert has been fully moved.
+pub fn migrate_expert(
+    env: &Env,
+    old_expert: &Address,
+    start: u32,
+    limit: u32,
+) -> Result<Option<u32>, VaultError> {
+    // 1. Ask the Identity Registry where the expert went
+    let registry = IdentityRegistryClient::new(
+        env,
+        &storage::get_registry(env).ok_or(VaultError::NotInitialized)?,
+    );
+    let new_expert = registry
+        .get_migrated_to(old_expert)
+        .ok_or(VaultError::ExpertNotMigrated)?;
+
+    // 2. Re-point this page's open bookings so payouts go to the new address
+    let bookings = storage::get_expert_bookings(env, old_expert);
+    let (cursor, mut moved) = storage::get_expert_migration(env, old_expert);
+    let end = bookings
+        .len()
+        .min(start.saturating_add(limit.clamp(1, EXPERT_MIGRATION_PAGE_SIZE)));
+    for booking_id in bookings.slice(start.min(end)..end).iter() {
+        let Some(mut booking) = storage::get_booking(env, booking_id) else {
+            continue;
+        };
+        if booking.expert != *old_expert || transitions::is_terminal(booking.status) {
+            continue;
+        }
+        booking.expert = new_expert.clone();
+        storage::save_booking(env, &booking);
+        moved += 1;
+    }
+
+    // 3. Remember progress until the whole list has been walked
+    let cursor = if start <= cursor {
+        cursor.max(end)
+    } else {
+        cursor
+    };
+    if cursor < bookings.len() {
+        storage::set_expert_migration(env, old_expert, cursor, moved);
+        return Ok(Some(cursor));
+    }
+    storage::remove_expert_migration(env, old_expert);
+
+    // 4. Carry the rate over unless the expert already set one at the new address
+    if let Some(rate) = storage::get_expert_rate(env, old_expert) {
+        if storage::get_expert_rate(env, &new_expert).is_none() {
+            storage::set_expert_rate(env, &new_expert, rate);
+        }
+        storage::remove_expert_rate(env, old_expert);
+    }
+
+    // 5. Hand the booking history over, finished bookings included
+    storage::append_expert_bookings(env, &new_expert, &bookings);
+    storage::remove_expert_bookings(env, old_expert);
+
+    // 6. Emit event
+    events::expert_migrated(env, old_expert, &new_expert, moved);
+
+    Ok(None)
+}
@@ -12,4 +12,19 @@ pub enum VaultError {
     InvalidAmount = 6,
     ReclaimTooEarly = 7,
     ExpertRateNotSet = 8,
+    ExpertNotVerified = 9,
+    SessionNotStarted = 10,
+    SessionAlreadyStarted = 11,
+    NothingToWithdraw = 12,
+    InvalidStatusTransition = 13,
+    InvalidFeeBps = 14,
+    ArbitratorNotSet = 15,
+    DisputeWindowClosed = 16,
+    BookingDisputed = 17,
+    NoPendingAdmin = 18,
+    BookingsStillOpen = 19,
+    AlreadyMigrated = 20,
+    ContractPaused = 21,
+    ExpertNotMigrated = 22,
+    DisputeWindowOpen = 23,
 }
@@ -1,5 +1,6 @@
 #![allow(deprecated)]
-use soroban_sdk::{symbol_short, Address, Env};
+use crate::types::PauseGroup;
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
 
 /// Emitted when a new booking is created
 pub fn booking_created(
@@ -15,9 +16,41 @@ pub fn booking_created(
 }
 
 /// Emitted when a session is finalized
-pub fn session_finalized(env: &Env, booking_id: u64, actual_duration: u64, total_cost: i128) {
+/// `fee` is the total platform fee taken from `total_cost` over the session
+pub fn session_finalized(
+    env: &Env,
+    booking_id: u64,
+    actual_duration: u64,
+    total_cost: i128,
+    fee: i128,
+) {
     let topics = (symbol_short!("finalized"), booking_id);
-    env.events().publish(topics, (actual_duration, total_cost));
+    env.events()
+        .publish(topics, (actual_duration, total_cost, fee));
+}
+
+/// Emitted when a finalized session is paid out after its dispute window
+pub fn session_settled(env: &Env, booking_id: u64, expert_pay: i128, refund: i128) {
+    let topics = (symbol_short!("settled"), booking_id);
+    env.events().publish(topics, (expert_pay, refund));
+}
+
+/// Emitted when an expert accepts a pending booking
+pub fn session_accepted(env: &Env, booking_id: u64, expert: &Address) {
+    let topics = (symbol_short!("accepted"), booking_id);
+    env.events().publish(topics, expert.clone());
+}
+
+/// Emitted when the Oracle starts a session and streaming begins
+pub fn session_started(env: &Env, booking_id: u64, started_at: u64) {
+    let topics = (symbol_short!("started"), booking_id);
+    env.events().publish(topics, started_at);
+}
+
+/// Emitted when an expert withdraws the amount accrued so far
+pub fn accrued_withdrawn(env: &Env, booking_id: u64, expert: &Address, amount: i128, fee: i128) {
+    let topics = (symbol_short!("withdraw"), booking_id);
+    env.events().publish(topics, (expert.clone(), amount, fee));
 }
 
 pub fn session_reclaimed(env: &Env, booking_id: u64, amount: i128) {
@@ -36,3 +69,100 @@ pub fn expert_rate_updated(env: &Env, expert: &Address, rate: i128) {
     let topics = (symbol_short!("rate_upd"), expert.clone());
     env.events().publish(topics, rate);
 }
+
+/// Emitted when the admin updates the platform fee configuration
+pub fn fee_config_updated(env: &Env, fee_bps: u32, treasury: &Address) {
+    let topics = (symbol_short!("fee_cfg"),);
+    env.events().publish(topics, (fee_bps, treasury.clone()));
+}
+
+/// Emitted when the admin appoints a new arbitrator
+pub fn arbitrator_updated(env: &Env, arbitrator: &Address) {
+    let topics = (symbol_short!("arb_set"),);
+    env.events().publish(topics, arbitrator.clone());
+}
+
+/// Emitted when the user or the expert disputes a booking
+pub fn dispute_opened(env: &Env, booking_id: u64, opened_by: &Address) {
+    let topics = (symbol_short!("disputed"), booking_id);
+    env.events().publish(topics, opened_by.clone());
+}
+
+/// Emitted when the arbitrator splits a disputed escrow
+pub fn dispute_resolved(env: &Env, booking_id: u64, expert_amount: i128, user_amount: i128) {
+    let topics = (symbol_short!("resolved"), booking_id);
+    env.events().publish(topics, (expert_amount, user_amount));
+}
+
+/// Emitted when the admin proposes a successor
+pub fn admin_proposed(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the proposed admin accepts the role
+pub fn admin_transferred(env: &Env, old_admin: &Address, new_admin: &Address) {
+    let topics = (symbol_short!("adm_xfer"),);
+    env.events()
+        .publish(topics, (old_admin.clone(), new_admin.clone()));
+}
+
+/// Emitted when the admin rotates the Oracle key
+pub fn oracle_updated(env: &Env, old_oracle: &Address, new_oracle: &Address) {
+    let topics = (symbol_short!("oracle"),);
+    env.events()
+        .publish(topics, (old_oracle.clone(), new_oracle.clone()));
+}
+
+/// Emitted when the admin changes the payment token
+pub fn token_updated(env: &Env, old_token: &Address, new_token: &Address) {
+    let topics = (symbol_short!("token"),);
+    env.events()
+        .publish(topics, (old_token.clone(), new_token.clone()));
+}
+
+/// Emitted when the admin points the vault at a different Identity Registry
+pub fn registry_updated(env: &Env, old_registry: &Option<Address>, new_registry: &Address) {
+    let topics = (symbol_short!("registry"),);
+    env.events()
+        .publish(topics, (old_registry.clone(), new_registry.clone()));
+}
+
+/// Emitted when the contract code is upgraded
+pub fn contract_upgraded(env: &Env, new_wasm_hash: &BytesN<32>) {
+    let topics = (symbol_short!("upgraded"),);
+    env.events().publish(topics, new_wasm_hash.clone());
+}
+
+/// Emitted when storage is migrated to a newer layout
+pub fn storage_migrated(env: &Env, from_version: u32, to_version: u32, records: u32) {
+    let topics = (symbol_short!("migrated"),);
+    env.events()
+        .publish(topics, (from_version, to_version, records));
+}
+
+/// Emitted when the admin appoints a new guardian
+pub fn guardian_updated(env: &Env, guardian: &Address) {
+    let topics = (symbol_short!("guardian"),);
+    env.events().publish(topics, guardian.clone());
+}
+
+/// Emitted when the admin or guardian pauses a group of entrypoints
+pub fn paused(env: &Env, group: PauseGroup, by: &Address) {
+    let topics = (symbol_short!("paused"), group);
+    env.events().publish(topics, by.clone());
+}
+
+/// Emitted when the admin or guardian lifts a pause
+pub fn unpaused(env: &Env, group: PauseGroup, by: &Address) {
+    let topics = (symbol_short!("unpaused"), group);
+    env.events().publish(topics, by.clone());
+}
+
+/// Emitted when an expert's rate and bookings follow them to a new address
+pub fn expert_migrated(env: &Env, old_expert: &Address, new_expert: &Address, bookings: u32) {
+    let topics = (symbol_short!("exp_moved"),);
+    env.events()
+        .publish(topics, (old_expert.clone(), new_expert.clone(), bookings));
+}
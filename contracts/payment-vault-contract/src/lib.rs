@@ -3,37 +3,99 @@
 mod contract;
 mod error;
 mod events;
+mod migration;
+mod registry;
 mod storage;
 #[cfg(test)]
 mod test;
+mod transitions;
 mod types;
 
 use crate::error::VaultError;
-use crate::types::BookingRecord;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use crate::types::{BookingRecord, FeeConfig, PauseGroup};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
 #[contract]
 pub struct PaymentVaultContract;
 
 #[contractimpl]
 impl PaymentVaultContract {
-    /// Initialize the vault with the Admin, the Payment Token, and the Oracle (Backend)
+    /// Initialize the vault with the Admin, the Payment Token, the Oracle (Backend)
+    /// and the Identity Registry used to check expert verification
     pub fn init(
         env: Env,
         admin: Address,
         token: Address,
         oracle: Address,
+        registry: Address,
     ) -> Result<(), VaultError> {
-        contract::initialize_vault(&env, &admin, &token, &oracle)
+        contract::initialize_vault(&env, &admin, &token, &oracle, &registry)
+    }
+
+    /// Upgrade the contract code to an uploaded wasm (Admin-only)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), VaultError> {
+        contract::upgrade(&env, new_wasm_hash)
+    }
+
+    /// Migrate bookings written by an older contract version, one page at a time (Admin-only)
+    /// Returns the offset to pass as `start` next, or None once the last page is
+    /// done and the version has been bumped
+    pub fn migrate(env: Env, start: u64, limit: u32) -> Result<Option<u64>, VaultError> {
+        contract::migrate(&env, start, limit)
+    }
+
+    /// Get the storage layout version
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
+    /// Propose a new admin (Admin-only)
+    /// The proposed address must call `accept_admin` to take over
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), VaultError> {
+        contract::propose_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin proposal (Proposed admin only)
+    pub fn accept_admin(env: Env) -> Result<(), VaultError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Rotate the Oracle key (Admin-only)
+    pub fn set_oracle(env: Env, oracle: Address) -> Result<(), VaultError> {
+        contract::set_oracle(&env, &oracle)
+    }
+
+    /// Point the vault at a different Identity Registry (Admin-only)
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), VaultError> {
+        contract::set_registry(&env, &registry)
+    }
+
+    /// Change the payment token (Admin-only)
+    /// Only allowed while no booking holds escrowed funds
+    pub fn set_token(env: Env, token: Address) -> Result<(), VaultError> {
+        contract::set_token(&env, &token)
+    }
+
+    /// Set the platform fee in basis points and the treasury that receives it (Admin-only)
+    /// Applies to bookings created after the change
+    pub fn set_fee_config(env: Env, fee_bps: u32, treasury: Address) -> Result<(), VaultError> {
+        contract::set_fee_config(&env, fee_bps, &treasury)
+    }
+
+    /// Get the current platform fee configuration
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        contract::get_fee_config(&env)
     }
 
     /// Set an expert's own rate per second
+    /// The expert must be verified in the Identity Registry
     pub fn set_my_rate(env: Env, expert: Address, rate_per_second: i128) -> Result<(), VaultError> {
         contract::set_my_rate(&env, &expert, rate_per_second)
     }
 
     /// Book a session with an expert
     /// User deposits tokens upfront based on rate_per_second * max_duration
+    /// Fails if the expert is not verified in the Identity Registry
     pub fn book_session(
         env: Env,
         user: Address,
@@ -43,8 +105,28 @@ impl PaymentVaultContract {
         contract::book_session(&env, &user, &expert, max_duration)
     }
 
+    /// Accept a pending session (Expert-only)
+    /// Signals that the expert has scheduled the session
+    pub fn accept_session(env: Env, expert: Address, booking_id: u64) -> Result<(), VaultError> {
+        contract::accept_session(&env, &expert, booking_id)
+    }
+
+    /// Start an accepted session (Oracle-only)
+    /// Records the start timestamp from which the expert's earnings accrue
+    pub fn start_session(env: Env, booking_id: u64) -> Result<(), VaultError> {
+        contract::start_session(&env, booking_id)
+    }
+
+    /// Withdraw the amount accrued so far in a started session (Expert-only)
+    /// Pays rate_per_second * elapsed seconds, capped at the deposit, minus prior withdrawals
+    /// Returns the amount received by the expert after the platform fee
+    pub fn withdraw_accrued(env: Env, booking_id: u64) -> Result<i128, VaultError> {
+        contract::withdraw_accrued(&env, booking_id)
+    }
+
     /// Finalize a session (Oracle-only)
-    /// Calculates payments based on actual duration and processes refunds
+    /// Records the payout for the actual duration; the escrow stays held for a
+    /// 24 hour dispute window before `settle_session` can pay it out
     pub fn finalize_session(
         env: Env,
         booking_id: u64,
@@ -53,8 +135,15 @@ impl PaymentVaultContract {
         contract::finalize_session(&env, booking_id, actual_duration)
     }
 
+    /// Settle a finalized session once its dispute window has closed (anyone)
+    /// Pays the expert what was not yet streamed and refunds the rest to the user
+    pub fn settle_session(env: Env, booking_id: u64) -> Result<(), VaultError> {
+        contract::settle_session(&env, booking_id)
+    }
+
     /// Reclaim funds from a stale booking (User-only)
-    /// Users can reclaim their deposit if the booking has been pending for more than 24 hours
+    /// Users can reclaim their deposit if the booking has been pending for more than 24 hours,
+    /// or accepted for more than 48 hours without the session starting
     pub fn reclaim_stale_session(
         env: Env,
         user: Address,
@@ -63,12 +152,69 @@ impl PaymentVaultContract {
         contract::reclaim_stale_session(&env, &user, booking_id)
     }
 
-    /// Reject a pending session (Expert-only)
-    /// Experts can reject a pending booking, instantly refunding the user
+    /// Reject a pending or accepted session (Expert-only)
+    /// Experts can reject a booking before it starts, instantly refunding the user
     pub fn reject_session(env: Env, expert: Address, booking_id: u64) -> Result<(), VaultError> {
         contract::reject_session(&env, &expert, booking_id)
     }
 
+    /// Appoint the arbitrator that resolves disputes (Admin-only)
+    pub fn set_arbitrator(env: Env, arbitrator: Address) -> Result<(), VaultError> {
+        contract::set_arbitrator(&env, &arbitrator)
+    }
+
+    /// Appoint the guardian that may pause and unpause alongside the admin (Admin-only)
+    pub fn set_guardian(env: Env, guardian: Address) -> Result<(), VaultError> {
+        contract::set_guardian(&env, &guardian)
+    }
+
+    /// Pause a group of entrypoints in an emergency (Admin or Guardian)
+    /// `reclaim_stale_session` and `reject_session` keep working so users can always get refunds,
+    /// and `withdraw_accrued` so experts can always collect what they already earned
+    pub fn pause(env: Env, caller: Address, group: PauseGroup) -> Result<(), VaultError> {
+        contract::pause(&env, &caller, group)
+    }
+
+    /// Lift the pause on a group of entrypoints (Admin or Guardian)
+    pub fn unpause(env: Env, caller: Address, group: PauseGroup) -> Result<(), VaultError> {
+        contract::unpause(&env, &caller, group)
+    }
+
+    /// Check whether a group of entrypoints is paused
+    pub fn is_paused(env: Env, group: PauseGroup) -> bool {
+        storage::is_paused(&env, group)
+    }
+
+    /// Dispute an in-progress or finalized session (User or Expert)
+    /// Freezes the escrow until the arbitrator resolves it, so an arbitrator must be appointed first
+    pub fn open_dispute(env: Env, caller: Address, booking_id: u64) -> Result<(), VaultError> {
+        contract::open_dispute(&env, &caller, booking_id)
+    }
+
+    /// Split a disputed escrow between expert and user (Arbitrator-only)
+    /// The two amounts must add up to everything still held for the booking
+    pub fn resolve_dispute(
+        env: Env,
+        booking_id: u64,
+        expert_amount: i128,
+        user_amount: i128,
+    ) -> Result<(), VaultError> {
+        contract::resolve_dispute(&env, booking_id, expert_amount, user_amount)
+    }
+
+    /// Move a migrated expert's rate and bookings to their new address, one page at a time (anyone)
+    /// The Identity Registry's forwarding pointer decides where they go; only open bookings are re-pointed.
+    /// `start` is a position in the old expert's booking list and `limit` is capped at 25.
+    /// Returns the position to pass as `start` next, or None once the expert has been fully moved
+    pub fn migrate_expert(
+        env: Env,
+        old_expert: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Option<u32>, VaultError> {
+        contract::migrate_expert(&env, &old_expert, start, limit)
+    }
+
     /// Get all booking IDs for a specific user
     pub fn get_user_bookings(env: Env, user: Address) -> Vec<u64> {
         storage::get_user_bookings(&env, &user)
@@ -0,0 +1,88 @@
+use crate::storage;
+use crate::types::{BookingRecord, BookingStatus};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Storage layout version written by this build
+pub const CONTRACT_VERSION: u32 = 2;
+
+/// Version assumed for vaults deployed before the version key existed
+pub const LEGACY_VERSION: u32 = 1;
+
+/// BookingRecord as written by version 1
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BookingRecordV1 {
+    pub id: u64,
+    pub user: Address,
+    pub expert: Address,
+    pub rate_per_second: i128,
+    pub max_duration: u64,
+    pub total_deposit: i128,
+    pub status: BookingStatus,
+    pub created_at: u64,
+}
+
+impl BookingRecordV1 {
+    /// Number of fields in the version 1 layout, used to tell it apart when read back
+    pub const FIELDS: u32 = 8;
+}
+
+impl From<BookingRecordV1> for BookingRecord {
+    fn from(old: BookingRecordV1) -> Self {
+        BookingRecord {
+            id: old.id,
+            user: old.user,
+            expert: old.expert,
+            rate_per_second: old.rate_per_second,
+            max_duration: old.max_duration,
+            total_deposit: old.total_deposit,
+            status: old.status,
+            created_at: old.created_at,
+            accepted_at: None,
+            started_at: None,
+            withdrawn: 0,
+            // Version 1 had no platform fee
+            fee_bps: 0,
+            finalized_at: None,
+            final_pay: 0,
+        }
+    }
+}
+
+/// Most bookings a single `migrate` call rewrites, keeping each call
+/// within the ledger entry limits of one transaction
+pub const MIGRATION_PAGE_SIZE: u32 = 25;
+
+/// Rewrite one page of bookings from the version 1 layout and add the ones
+/// still holding escrow to the open-bookings counter. `start` counts bookings
+/// already walked, so the page covers IDs `start + 1` onwards.
+///
+/// Bookings already in the current layout are left alone, so pages can be
+/// retried, and only a page starting at or before the stored cursor moves it
+/// forward. Returns the offset to continue from, or None once every booking
+/// has been walked, together with the records rewritten so far.
+pub fn migrate_from_v1(env: &Env, start: u64, limit: u32) -> (Option<u64>, u32) {
+    let total = storage::get_booking_counter(env);
+    let (cursor, mut migrated) = storage::get_migration_progress(env);
+    let end = total.min(start.saturating_add(limit.clamp(1, MIGRATION_PAGE_SIZE) as u64));
+
+    for booking_id in start + 1..=end {
+        // Saving over a version 1 booking adds it to the open count if it holds escrow
+        if let Some(old) = storage::get_legacy_booking(env, booking_id) {
+            storage::save_booking(env, &old.into());
+            migrated += 1;
+        }
+    }
+
+    let cursor = if start <= cursor {
+        cursor.max(end)
+    } else {
+        cursor
+    };
+    if cursor < total {
+        storage::set_migration_progress(env, cursor, migrated);
+        return (Some(cursor), migrated);
+    }
+    storage::remove_migration_progress(env);
+    (None, migrated)
+}
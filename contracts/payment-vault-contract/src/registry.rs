@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Subset of the Identity Registry interface the vault depends on
+#[allow(dead_code)]
+#[contractclient(name = "IdentityRegistryClient")]
+pub trait IdentityRegistry {
+    /// Returns true only if the expert's status is Verified
+    fn is_verified(env: Env, expert: Address) -> bool;
+
+    /// Returns the address a migrated expert moved to
+    fn get_migrated_to(env: Env, old: Address) -> Option<Address>;
+}
@@ -1,17 +1,30 @@
-use crate::types::{BookingRecord, BookingStatus};
-use soroban_sdk::{contracttype, Address, Env};
+use crate::migration::{BookingRecordV1, CONTRACT_VERSION, LEGACY_VERSION};
+use crate::transitions;
+use crate::types::{BookingRecord, BookingStatus, PauseGroup};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val};
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
+    PendingAdmin, // Proposed admin awaiting acceptance
     Token,
     Oracle,
-    Booking(u64),            // Booking ID -> BookingRecord
-    BookingCounter,          // Counter for generating unique booking IDs
-    UserBookings(Address),   // User Address -> Vec<u64> of booking IDs
-    ExpertBookings(Address), // Expert Address -> Vec<u64> of booking IDs
-    ExpertRate(Address),     // Expert Address -> rate per second (i128)
+    Registry,                 // Identity Registry contract address
+    FeeBps,                   // Platform fee in basis points (u32)
+    Treasury,                 // Platform fee recipient
+    Arbitrator,               // Resolves disputed bookings
+    Guardian,                 // May pause and unpause alongside the admin
+    Paused(PauseGroup),       // Pause flag per entrypoint group (bool)
+    Booking(u64),             // Booking ID -> BookingRecord
+    BookingCounter,           // Counter for generating unique booking IDs
+    OpenBookings,             // Number of bookings still holding escrow (u64)
+    ContractVersion,          // Storage layout version (u32)
+    MigrationProgress,        // Next booking offset and records rewritten by a paged migration
+    UserBookings(Address),    // User Address -> Vec<u64> of booking IDs
+    ExpertBookings(Address),  // Expert Address -> Vec<u64> of booking IDs
+    ExpertMigration(Address), // Old expert -> next list position and bookings moved by a paged migrate_expert
+    ExpertRate(Address),      // Expert Address -> rate per second (i128)
 }
 
 // --- Admin ---
@@ -23,18 +36,29 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
 
-#[allow(dead_code)]
 pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Admin)
 }
 
+pub fn set_pending_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::PendingAdmin, admin);
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
 // --- Token (USDC/XLM) ---
 pub fn set_token(env: &Env, token: &Address) {
     env.storage().instance().set(&DataKey::Token, token);
 }
 
-pub fn get_token(env: &Env) -> Address {
-    env.storage().instance().get(&DataKey::Token).unwrap()
+pub fn get_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Token)
 }
 
 // --- Oracle (Backend) ---
@@ -42,11 +66,108 @@ pub fn set_oracle(env: &Env, oracle: &Address) {
     env.storage().instance().set(&DataKey::Oracle, oracle);
 }
 
-pub fn get_oracle(env: &Env) -> Address {
-    env.storage().instance().get(&DataKey::Oracle).unwrap()
+pub fn get_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Oracle)
+}
+
+// --- Identity Registry ---
+pub fn set_registry(env: &Env, registry: &Address) {
+    env.storage().instance().set(&DataKey::Registry, registry);
+}
+
+pub fn get_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+// --- Platform Fee ---
+pub fn set_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+}
+
+pub fn get_fee_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+}
+
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    env.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+// --- Arbitrator ---
+pub fn set_arbitrator(env: &Env, arbitrator: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Arbitrator, arbitrator);
+}
+
+pub fn get_arbitrator(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Arbitrator)
+}
+
+// --- Emergency Pause ---
+pub fn set_guardian(env: &Env, guardian: &Address) {
+    env.storage().instance().set(&DataKey::Guardian, guardian);
+}
+
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Guardian)
+}
+
+pub fn set_paused(env: &Env, group: PauseGroup, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Paused(group), &paused);
+}
+
+pub fn is_paused(env: &Env, group: PauseGroup) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused(group))
+        .unwrap_or(false)
+}
+
+// --- Contract Version ---
+pub fn set_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ContractVersion, &version);
+}
+
+pub fn get_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractVersion)
+        .unwrap_or(LEGACY_VERSION)
+}
+
+pub fn get_migration_progress(env: &Env) -> (u64, u32) {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationProgress)
+        .unwrap_or((0, 0))
+}
+
+pub fn set_migration_progress(env: &Env, cursor: u64, records: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationProgress, &(cursor, records));
+}
+
+pub fn remove_migration_progress(env: &Env) {
+    env.storage().instance().remove(&DataKey::MigrationProgress);
 }
 
 // --- Booking Counter ---
+pub fn get_booking_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BookingCounter)
+        .unwrap_or(0)
+}
+
 pub fn get_next_booking_id(env: &Env) -> u64 {
     let current: u64 = env
         .storage()
@@ -60,19 +181,59 @@ pub fn get_next_booking_id(env: &Env) -> u64 {
     next
 }
 
+// --- Open Bookings ---
+pub fn get_open_bookings(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OpenBookings)
+        .unwrap_or(0)
+}
+
+pub fn set_open_bookings(env: &Env, count: u64) {
+    env.storage().instance().set(&DataKey::OpenBookings, &count);
+}
+
 // --- Bookings ---
 pub fn save_booking(env: &Env, booking: &BookingRecord) {
+    // A version 1 booking rewritten before `migrate` reaches it is skipped by the
+    // migration, so it joins the open-bookings count here instead
+    if get_version(env) < CONTRACT_VERSION {
+        if let Some(old) = get_legacy_booking(env, booking.id) {
+            if !transitions::is_terminal(old.status) {
+                set_open_bookings(env, get_open_bookings(env) + 1);
+            }
+        }
+    }
+
     env.storage()
         .persistent()
         .set(&DataKey::Booking(booking.id), booking);
 }
 
 pub fn get_booking(env: &Env, booking_id: u64) -> Option<BookingRecord> {
+    // Bookings not yet rewritten by `migrate` are converted on read
+    if let Some(old) = get_legacy_booking(env, booking_id) {
+        return Some(old.into());
+    }
     env.storage()
         .persistent()
         .get(&DataKey::Booking(booking_id))
 }
 
+/// Get a booking still stored in the version 1 layout, telling the layouts
+/// apart by field count since decoding the wrong one traps
+pub fn get_legacy_booking(env: &Env, booking_id: u64) -> Option<BookingRecordV1> {
+    let fields: Map<Symbol, Val> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Booking(booking_id))?;
+    if fields.len() != BookingRecordV1::FIELDS {
+        return None;
+    }
+    let val: Val = fields.into_val(env);
+    BookingRecordV1::try_from_val(env, &val).ok()
+}
+
 pub fn update_booking_status(env: &Env, booking_id: u64, status: BookingStatus) {
     if let Some(mut booking) = get_booking(env, booking_id) {
         booking.status = status;
@@ -123,6 +284,41 @@ pub fn get_expert_bookings(env: &Env, expert: &Address) -> soroban_sdk::Vec<u64>
         .unwrap_or(soroban_sdk::Vec::new(env))
 }
 
+pub fn remove_expert_bookings(env: &Env, expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ExpertBookings(expert.clone()));
+}
+
+pub fn append_expert_bookings(env: &Env, expert: &Address, booking_ids: &soroban_sdk::Vec<u64>) {
+    let mut expert_bookings = get_expert_bookings(env, expert);
+    expert_bookings.append(booking_ids);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ExpertBookings(expert.clone()), &expert_bookings);
+}
+
+// --- Expert Migration Progress ---
+pub fn get_expert_migration(env: &Env, old_expert: &Address) -> (u32, u32) {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ExpertMigration(old_expert.clone()))
+        .unwrap_or((0, 0))
+}
+
+pub fn set_expert_migration(env: &Env, old_expert: &Address, cursor: u32, moved: u32) {
+    env.storage().persistent().set(
+        &DataKey::ExpertMigration(old_expert.clone()),
+        &(cursor, moved),
+    );
+}
+
+pub fn remove_expert_migration(env: &Env, old_expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ExpertMigration(old_expert.clone()));
+}
+
 // --- Expert Rates ---
 pub fn set_expert_rate(env: &Env, expert: &Address, rate: i128) {
     env.storage()
@@ -135,3 +331,9 @@ pub fn get_expert_rate(env: &Env, expert: &Address) -> Option<i128> {
         .persistent()
         .get(&DataKey::ExpertRate(expert.clone()))
 }
+
+pub fn remove_expert_rate(env: &Env, expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ExpertRate(expert.clone()));
+}
@@ -1,12 +1,24 @@
 #![cfg(test)]
+use crate::error::VaultError;
+use crate::transitions::{RECLAIM_TIMEOUT, SETTLEMENT_WINDOW};
+use crate::types::BookingStatus;
 use crate::{PaymentVaultContract, PaymentVaultContractClient};
+use identity_registry_contract::{IdentityRegistryContract, IdentityRegistryContractClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    token, Address, Env,
+    token, vec, Address, BytesN, Env, String,
 };
 
 extern crate std;
 
+// Vault built with `cargo build --release --target wasm32v1-none`, used as the upgrade target
+const VAULT_WASM: &[u8] = include_bytes!("../test_fixtures/payment_vault_contract.wasm");
+
+// Version 1 vault built from the baseline commit, used as the upgrade source
+mod v1 {
+    soroban_sdk::contractimport!(file = "test_fixtures/payment_vault_contract_v1.wasm");
+}
+
 fn create_client<'a>(env: &'a Env) -> PaymentVaultContractClient<'a> {
     let contract_id = env.register(PaymentVaultContract, ());
     PaymentVaultContractClient::new(env, &contract_id)
@@ -17,6 +29,34 @@ fn create_token_contract<'a>(env: &'a Env, admin: &Address) -> token::StellarAss
     token::StellarAssetClient::new(env, &contract.address())
 }
 
+fn create_registry<'a>(env: &'a Env, admin: &Address) -> IdentityRegistryContractClient<'a> {
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let registry = IdentityRegistryContractClient::new(env, &contract_id);
+    registry.init(admin);
+    registry
+}
+
+fn verify_expert(
+    env: &Env,
+    registry: &IdentityRegistryContractClient,
+    admin: &Address,
+    expert: &Address,
+) {
+    registry.add_expert(
+        admin,
+        expert,
+        &String::from_str(env, "ipfs://expert"),
+        &BytesN::from_array(env, &[0u8; 32]),
+    );
+}
+
+/// Let the dispute window of a finalized booking run out, then pay it out
+fn settle_after_window(env: &Env, client: &PaymentVaultContractClient, booking_id: u64) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SETTLEMENT_WINDOW + 1);
+    client.settle_session(&booking_id);
+}
+
 #[test]
 fn test_initialization() {
     let env = Env::default();
@@ -25,13 +65,14 @@ fn test_initialization() {
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
     let oracle = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
 
     // 1. Successful Init
-    let res = client.try_init(&admin, &token, &oracle);
+    let res = client.try_init(&admin, &token, &oracle, &registry.address);
     assert!(res.is_ok());
 
     // 2. Double Init (Should Fail)
-    let res_duplicate = client.try_init(&admin, &token, &oracle);
+    let res_duplicate = client.try_init(&admin, &token, &oracle, &registry.address);
     assert!(res_duplicate.is_err());
 }
 
@@ -52,7 +93,9 @@ fn test_partial_duration_scenario() {
 
     // Initialize vault
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Book session: rate = 10 tokens/second, max_duration = 100 seconds
     // Total deposit = 10 * 100 = 1000 tokens
@@ -67,10 +110,24 @@ fn test_partial_duration_scenario() {
     assert_eq!(token.balance(&user), 9_000);
     assert_eq!(token.balance(&client.address), 1_000);
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Oracle finalizes with 50% of booked time (50 seconds)
     let actual_duration = 50_u64;
     client.finalize_session(&booking_id, &actual_duration);
 
+    // Nothing moves until the dispute window has passed
+    assert_eq!(token.balance(&client.address), 1_000);
+    let res = client.try_settle_session(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::DisputeWindowOpen)));
+    settle_after_window(&env, &client, booking_id);
+    assert_eq!(
+        client.get_booking(&booking_id).unwrap().status,
+        BookingStatus::Complete
+    );
+
     // Expected: expert_pay = 10 * 50 = 500, refund = 1000 - 500 = 500
     assert_eq!(token.balance(&expert), 500);
     assert_eq!(token.balance(&user), 9_500); // 9000 + 500 refund
@@ -92,7 +149,9 @@ fn test_full_duration_no_refund() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Book session
     let rate_per_second = 10_i128;
@@ -102,9 +161,14 @@ fn test_full_duration_no_refund() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Oracle finalizes with full duration (100 seconds)
     let actual_duration = 100_u64;
     client.finalize_session(&booking_id, &actual_duration);
+    settle_after_window(&env, &client, booking_id);
 
     // Expected: expert_pay = 10 * 100 = 1000, refund = 0
     assert_eq!(token.balance(&expert), 1_000);
@@ -127,7 +191,9 @@ fn test_double_finalization_protection() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -136,6 +202,10 @@ fn test_double_finalization_protection() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // First finalization succeeds
     let actual_duration = 50_u64;
     let result = client.try_finalize_session(&booking_id, &actual_duration);
@@ -161,7 +231,9 @@ fn test_oracle_authorization_enforcement() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -170,6 +242,10 @@ fn test_oracle_authorization_enforcement() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Clear all mocked auths to test Oracle authorization
     env.set_auths(&[]);
 
@@ -181,6 +257,10 @@ fn test_oracle_authorization_enforcement() {
     env.mock_all_auths();
     client.finalize_session(&booking_id, &50);
 
+    // Settlement needs no Oracle auth once the dispute window has passed
+    env.set_auths(&[]);
+    settle_after_window(&env, &client, booking_id);
+
     // Verify finalization succeeded
     assert_eq!(token.balance(&expert), 500);
 }
@@ -200,7 +280,9 @@ fn test_zero_duration_finalization() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -209,9 +291,14 @@ fn test_zero_duration_finalization() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Oracle finalizes with 0 duration (session cancelled)
     let actual_duration = 0_u64;
     client.finalize_session(&booking_id, &actual_duration);
+    settle_after_window(&env, &client, booking_id);
 
     // Expected: expert_pay = 0, full refund to user
     assert_eq!(token.balance(&expert), 0);
@@ -229,7 +316,8 @@ fn test_booking_not_found() {
     let token = Address::generate(&env);
 
     let client = create_client(&env);
-    client.init(&admin, &token, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token, &oracle, &registry.address);
 
     // Try to finalize non-existent booking
     let result = client.try_finalize_session(&999, &50);
@@ -258,7 +346,9 @@ fn test_book_session_balance_transfer() {
     token.mint(&user, &initial_balance);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Book session with specific deposit
     let rate_per_second = 5_i128;
@@ -312,7 +402,10 @@ fn test_get_user_and_expert_bookings() {
     token.mint(&user, &100_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert1);
+    verify_expert(&env, &registry, &admin, &expert2);
 
     // Create 2 bookings for the same user with different experts
     let rate_per_second = 10_i128;
@@ -371,7 +464,9 @@ fn test_reclaim_stale_session_too_early() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Create booking
     let rate_per_second = 10_i128;
@@ -405,7 +500,9 @@ fn test_reclaim_stale_session_success() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Create booking
     let rate_per_second = 10_i128;
@@ -445,7 +542,9 @@ fn test_reclaim_stale_session_wrong_user() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Create booking
     let rate_per_second = 10_i128;
@@ -482,7 +581,9 @@ fn test_reclaim_already_finalized() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Create booking
     let rate_per_second = 10_i128;
@@ -492,6 +593,10 @@ fn test_reclaim_already_finalized() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Oracle finalizes the session
     client.finalize_session(&booking_id, &50);
 
@@ -519,7 +624,9 @@ fn test_expert_rejects_pending_session() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Create booking
     let rate_per_second = 10_i128;
@@ -563,7 +670,9 @@ fn test_user_cannot_reject_session() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -595,7 +704,9 @@ fn test_reject_already_complete_session() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -604,6 +715,10 @@ fn test_reject_already_complete_session() {
         client.book_session(&user, &expert, &max_duration)
     };
 
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
     // Oracle finalizes the session
     client.finalize_session(&booking_id, &50);
 
@@ -627,7 +742,9 @@ fn test_reject_already_reclaimed_session() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -662,7 +779,9 @@ fn test_wrong_expert_cannot_reject() {
     token.mint(&user, &10_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     let rate_per_second = 10_i128;
     let max_duration = 100_u64;
@@ -690,7 +809,8 @@ fn test_reject_nonexistent_booking() {
     let oracle = Address::generate(&env);
 
     let client = create_client(&env);
-    client.init(&admin, &token, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token, &oracle, &registry.address);
 
     // Expert tries to reject non-existent booking (should fail - not found)
     let result = client.try_reject_session(&expert, &999);
@@ -708,7 +828,9 @@ fn test_expert_can_set_and_update_rate() {
     let token = Address::generate(&env);
 
     let client = create_client(&env);
-    client.init(&admin, &token, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Initial set
     let res1 = client.try_set_my_rate(&expert, &10_i128);
@@ -739,7 +861,9 @@ fn test_book_session_calculates_correct_deposit() {
     token.mint(&user, &initial_balance);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
 
     // Set expert rate
     let stored_rate = 15_i128;
@@ -749,7 +873,7 @@ fn test_book_session_calculates_correct_deposit() {
     let max_duration = 100_u64;
     let expected_deposit = stored_rate * (max_duration as i128); // 1500 tokens
 
-    let booking_id = client.book_session(&user, &expert, &max_duration);
+    client.book_session(&user, &expert, &max_duration);
 
     // Verify correct deposit was extracted
     assert_eq!(token.balance(&user), initial_balance - expected_deposit);
@@ -771,7 +895,8 @@ fn test_book_session_fails_if_expert_rate_not_set() {
     token.mint(&user, &5_000);
 
     let client = create_client(&env);
-    client.init(&admin, &token.address, &oracle);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
 
     // Expert has NOT set rate
 
@@ -781,3 +906,1290 @@ fn test_book_session_fails_if_expert_rate_not_set() {
 
     assert!(res.is_err());
 }
+
+#[test]
+fn test_verified_expert_can_be_booked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    // Deploy the registry and the vault side by side
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+
+    verify_expert(&env, &registry, &admin, &expert);
+    assert!(registry.is_verified(&expert));
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+
+    assert_eq!(booking_id, 1);
+    assert_eq!(token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_unverified_expert_cannot_set_rate_or_be_booked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+
+    // Expert was never added to the registry
+    let res = client.try_set_my_rate(&expert, &10_i128);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotVerified)));
+
+    let res = client.try_book_session(&user, &expert, &100);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotVerified)));
+
+    // No funds moved
+    assert_eq!(token.balance(&user), 10_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_banned_expert_cannot_set_rate_or_be_booked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+
+    // Expert sets a rate while verified, then gets banned
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_my_rate(&expert, &10_i128);
+    registry.ban_expert(&admin, &expert);
+
+    let res = client.try_set_my_rate(&expert, &20_i128);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotVerified)));
+
+    // The stale rate must not allow new bookings
+    let res = client.try_book_session(&user, &expert, &100);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotVerified)));
+
+    assert_eq!(token.balance(&user), 10_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_streaming_withdrawals_and_finalize_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    // rate = 10/s, max_duration = 100s, deposit = 1000
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+
+    // Expert accepts and the Oracle starts the session
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    let booking = client.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.started_at, Some(env.ledger().timestamp()));
+
+    // 20 seconds later the expert withdraws 200
+    env.ledger().set_timestamp(env.ledger().timestamp() + 20);
+    assert_eq!(client.withdraw_accrued(&booking_id), 200);
+    assert_eq!(token.balance(&expert), 200);
+
+    // Withdrawing again in the same second has nothing to pay
+    let res = client.try_withdraw_accrued(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::NothingToWithdraw)));
+
+    // 15 more seconds: only the delta is paid
+    env.ledger().set_timestamp(env.ledger().timestamp() + 15);
+    assert_eq!(client.withdraw_accrued(&booking_id), 150);
+    assert_eq!(token.balance(&expert), 350);
+    assert_eq!(client.get_booking(&booking_id).unwrap().withdrawn, 350);
+
+    // Oracle finalizes at 50 seconds: expert gets the remaining 150, user the 500 refund
+    client.finalize_session(&booking_id, &50);
+    settle_after_window(&env, &client, booking_id);
+    assert_eq!(token.balance(&expert), 500);
+    assert_eq!(token.balance(&user), 9_500);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_withdraw_accrued_capped_at_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
+    // Well past max_duration: accrual stops at the deposit
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+    assert_eq!(client.withdraw_accrued(&booking_id), 1_000);
+    assert_eq!(token.balance(&client.address), 0);
+
+    // Finalizing settles nothing further and never refunds streamed funds
+    client.finalize_session(&booking_id, &100);
+    settle_after_window(&env, &client, booking_id);
+    assert_eq!(token.balance(&expert), 1_000);
+    assert_eq!(token.balance(&user), 9_000);
+}
+
+#[test]
+fn test_withdraw_accrued_requires_started_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 50);
+    let res = client.try_withdraw_accrued(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::SessionNotStarted)));
+
+    // Starting twice is rejected
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    let res = client.try_start_session(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::SessionAlreadyStarted)));
+
+    // No withdrawals after the session is finalized
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+    client.finalize_session(&booking_id, &10);
+    let res = client.try_withdraw_accrued(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::BookingNotPending)));
+}
+
+#[test]
+fn test_reclaim_after_partial_stream_refunds_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+    client.withdraw_accrued(&booking_id);
+
+    // Oracle never finalizes; user reclaims what was not streamed
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 90_000);
+    client.reclaim_stale_session(&user, &booking_id);
+
+    assert_eq!(token.balance(&expert), 300);
+    assert_eq!(token.balance(&user), 9_700);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_accept_session_moves_to_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let wrong_expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+
+    // Only the booked expert can accept
+    let res = client.try_accept_session(&wrong_expert, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::NotAuthorized)));
+
+    client.accept_session(&expert, &booking_id);
+    let booking = client.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.status, BookingStatus::Accepted);
+    assert_eq!(booking.accepted_at, Some(env.ledger().timestamp()));
+
+    // Accepting twice is not a valid move
+    let res = client.try_accept_session(&expert, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    client.start_session(&booking_id);
+    let booking = client.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.status, BookingStatus::InProgress);
+}
+
+#[test]
+fn test_finalize_requires_session_in_progress() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+
+    // Pending -> Complete is not allowed
+    let res = client.try_finalize_session(&booking_id, &50);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    // Pending -> InProgress skips acceptance
+    let res = client.try_start_session(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    // Accepted -> Complete is not allowed either
+    client.accept_session(&expert, &booking_id);
+    let res = client.try_finalize_session(&booking_id, &50);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    assert_eq!(token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_reclaim_after_acceptance_waits_for_no_show_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+
+    // 25 hours later: a pending booking would be reclaimable, an accepted one is not
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 90_000);
+    let res = client.try_reclaim_stale_session(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::ReclaimTooEarly)));
+
+    // Past the 48 hour no-show window the user can reclaim
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 90_000);
+    client.reclaim_stale_session(&user, &booking_id);
+
+    assert_eq!(token.balance(&user), 10_000);
+    assert_eq!(token.balance(&client.address), 0);
+    let booking = client.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.status, BookingStatus::Reclaimed);
+}
+
+#[test]
+fn test_reject_allowed_after_accept_but_not_after_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_my_rate(&expert, &10_i128);
+
+    // Accepted bookings can still be rejected with a full refund
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.reject_session(&expert, &booking_id);
+    assert_eq!(token.balance(&user), 10_000);
+
+    // Once the session is in progress, rejection is no longer possible
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    let res = client.try_reject_session(&expert, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+    assert_eq!(token.balance(&client.address), 1_000);
+}
+
+#[test]
+fn test_transition_table() {
+    use crate::transitions::can_transition;
+    use BookingStatus::*;
+
+    assert!(can_transition(Pending, Accepted));
+    assert!(can_transition(Pending, Rejected));
+    assert!(can_transition(Pending, Reclaimed));
+    assert!(can_transition(Accepted, InProgress));
+    assert!(can_transition(Accepted, Rejected));
+    assert!(can_transition(Accepted, Reclaimed));
+    assert!(can_transition(InProgress, Finalized));
+    assert!(can_transition(InProgress, Reclaimed));
+    assert!(can_transition(InProgress, Disputed));
+    assert!(can_transition(Finalized, Complete));
+    assert!(can_transition(Finalized, Disputed));
+
+    assert!(!can_transition(Pending, InProgress));
+    assert!(!can_transition(Pending, Complete));
+    assert!(!can_transition(Accepted, Complete));
+    assert!(!can_transition(Accepted, Disputed));
+    assert!(!can_transition(InProgress, Complete));
+    assert!(!can_transition(InProgress, Rejected));
+    assert!(!can_transition(Finalized, Reclaimed));
+
+    // Terminal states never move
+    for terminal in [Complete, Rejected, Reclaimed, Resolved] {
+        for to in [
+            Pending, Accepted, InProgress, Finalized, Complete, Rejected, Reclaimed, Disputed,
+            Resolved,
+        ] {
+            assert!(!can_transition(terminal, to));
+        }
+    }
+}
+
+#[test]
+fn test_fee_config_admin_only_and_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token, &oracle, &registry.address);
+
+    // Defaults to no fee
+    let config = client.get_fee_config();
+    assert_eq!(config.fee_bps, 0);
+    assert_eq!(config.treasury, None);
+
+    client.set_fee_config(&250, &treasury);
+    let config = client.get_fee_config();
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.treasury, Some(treasury.clone()));
+
+    // More than 100% is rejected
+    let res = client.try_set_fee_config(&10_001, &treasury);
+    assert_eq!(res, Err(Ok(VaultError::InvalidFeeBps)));
+
+    // Without admin auth the call fails
+    env.set_auths(&[]);
+    let res = client.try_set_fee_config(&100, &treasury);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_finalize_splits_fee_to_treasury_and_refund_is_fee_free() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    // 2.5% fee
+    client.set_fee_config(&250, &treasury);
+
+    // rate = 7/s, max_duration = 100s, deposit = 700
+    client.set_my_rate(&expert, &7_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
+    // 33 seconds: expert_pay = 231, fee = floor(231 * 250 / 10000) = 5
+    client.finalize_session(&booking_id, &33);
+    settle_after_window(&env, &client, booking_id);
+
+    assert_eq!(token.balance(&treasury), 5);
+    assert_eq!(token.balance(&expert), 226);
+    // Refund of 469 is paid in full
+    assert_eq!(token.balance(&user), 9_300 + 469);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_streamed_fee_matches_single_payout_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    // 1% fee
+    client.set_fee_config(&100, &treasury);
+
+    client.set_my_rate(&expert, &3_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
+    // Withdraw in small chunks whose individual fees would round to zero
+    for _ in 0..5 {
+        env.ledger().set_timestamp(env.ledger().timestamp() + 11);
+        client.withdraw_accrued(&booking_id);
+    }
+
+    // 55 seconds streamed (165 gross), finalize at 60 seconds (180 gross)
+    client.finalize_session(&booking_id, &60);
+    settle_after_window(&env, &client, booking_id);
+
+    // floor(180 * 100 / 10000) = 1, exactly as if paid once
+    assert_eq!(token.balance(&treasury), 1);
+    assert_eq!(token.balance(&expert), 179);
+    assert_eq!(token.balance(&user), 9_700 + 120);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_fee_never_charged_on_reject_or_reclaim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_fee_config(&1_000, &treasury);
+    client.set_my_rate(&expert, &10_i128);
+
+    // Rejected booking: full refund, no fee
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.reject_session(&expert, &booking_id);
+
+    // Reclaimed booking: full refund, no fee
+    let booking_id = client.book_session(&user, &expert, &100);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 90_000);
+    client.reclaim_stale_session(&user, &booking_id);
+
+    // Zero-duration finalization: full refund, no fee
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    client.finalize_session(&booking_id, &0);
+    settle_after_window(&env, &client, booking_id);
+
+    assert_eq!(token.balance(&user), 10_000);
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(token.balance(&expert), 0);
+}
+
+#[test]
+fn test_dispute_freezes_funds_until_arbitrator_resolves() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_arbitrator(&arbitrator);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+
+    // Expert streams 200 before the user disputes
+    env.ledger().set_timestamp(env.ledger().timestamp() + 20);
+    client.withdraw_accrued(&booking_id);
+    client.open_dispute(&user, &booking_id);
+    assert_eq!(
+        client.get_booking(&booking_id).unwrap().status,
+        BookingStatus::Disputed
+    );
+
+    // Funds are frozen: no withdrawal, finalization or reclaim
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+    let res = client.try_withdraw_accrued(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::BookingDisputed)));
+    let res = client.try_finalize_session(&booking_id, &30);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 200_000);
+    let res = client.try_reclaim_stale_session(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+    assert_eq!(token.balance(&client.address), 800);
+
+    // Split must cover exactly the 800 still in escrow
+    let res = client.try_resolve_dispute(&booking_id, &300, &400);
+    assert_eq!(res, Err(Ok(VaultError::InvalidAmount)));
+
+    client.resolve_dispute(&booking_id, &300, &500);
+    assert_eq!(token.balance(&expert), 500);
+    assert_eq!(token.balance(&user), 9_500);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.get_booking(&booking_id).unwrap().status,
+        BookingStatus::Resolved
+    );
+
+    // Resolved is terminal
+    let res = client.try_resolve_dispute(&booking_id, &0, &0);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_open_dispute_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_my_rate(&expert, &10_i128);
+
+    // Without an arbitrator a dispute could never be resolved, so none can be opened
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    let res = client.try_open_dispute(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::ArbitratorNotSet)));
+    let res = client.try_resolve_dispute(&booking_id, &0, &1_000);
+    assert_eq!(res, Err(Ok(VaultError::ArbitratorNotSet)));
+    client.set_arbitrator(&Address::generate(&env));
+
+    // Only the user or the expert can open a dispute
+    let res = client.try_open_dispute(&stranger, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::NotAuthorized)));
+
+    // The window closes once the booking becomes reclaimable
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 100 + RECLAIM_TIMEOUT + 1);
+    let res = client.try_open_dispute(&expert, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::DisputeWindowClosed)));
+
+    // Nothing to dispute before a session starts; the parties can reject or reclaim instead
+    let booking_id = client.book_session(&user, &expert, &100);
+    let res = client.try_open_dispute(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+    client.accept_session(&expert, &booking_id);
+    let res = client.try_open_dispute(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    // Expert can dispute an in-progress session within the window
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    client.open_dispute(&expert, &booking_id);
+
+    // The duration reported by the Oracle can be disputed until settlement
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    client.finalize_session(&booking_id, &50);
+    client.open_dispute(&user, &booking_id);
+    assert_eq!(
+        client.get_booking(&booking_id).unwrap().status,
+        BookingStatus::Disputed
+    );
+    let res = client.try_settle_session(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+
+    // Once the window after finalization closes, the session settles instead
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    client.finalize_session(&booking_id, &50);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SETTLEMENT_WINDOW + 1);
+    let res = client.try_open_dispute(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::DisputeWindowClosed)));
+    client.settle_session(&booking_id);
+
+    // Completed sessions cannot be disputed
+    let res = client.try_open_dispute(&user, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_dispute_after_finalize_splits_held_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_arbitrator(&arbitrator);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    client.start_session(&booking_id);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 20);
+    client.withdraw_accrued(&booking_id);
+
+    // Oracle reports the full 100 seconds; the user says it was 30
+    client.finalize_session(&booking_id, &100);
+    let booking = client.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.status, BookingStatus::Finalized);
+    assert_eq!(booking.final_pay, 1_000);
+    assert_eq!(token.balance(&client.address), 800);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SETTLEMENT_WINDOW);
+    client.open_dispute(&user, &booking_id);
+
+    // The arbitrator splits what is still held, overriding the Oracle's figure
+    client.resolve_dispute(&booking_id, &100, &700);
+    assert_eq!(token.balance(&expert), 300);
+    assert_eq!(token.balance(&user), 9_700);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_entrypoints_return_not_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let client = create_client(&env);
+
+    let res = client.try_set_my_rate(&expert, &10_i128);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_book_session(&user, &expert, &100);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_start_session(&1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_finalize_session(&1, &50);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_set_oracle(&user);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_set_token(&user);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_propose_admin(&user);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    // Booking entrypoints report the missing init rather than a missing booking
+    let res = client.try_accept_session(&expert, &1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_withdraw_accrued(&1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_settle_session(&1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_reclaim_stale_session(&user, &1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_reject_session(&expert, &1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_open_dispute(&user, &1);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+
+    let res = client.try_resolve_dispute(&1, &0, &0);
+    assert_eq!(res, Err(Ok(VaultError::NotInitialized)));
+}
+
+#[test]
+fn test_set_oracle_rotates_finalization_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let new_oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+
+    client.set_oracle(&new_oracle);
+    assert_eq!(env.auths()[0].0, admin);
+
+    client.set_my_rate(&expert, &10_i128);
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+
+    // The new Oracle is the one that must authorize session control
+    client.start_session(&booking_id);
+    assert_eq!(env.auths()[0].0, new_oracle);
+    client.finalize_session(&booking_id, &50);
+    assert_eq!(env.auths()[0].0, new_oracle);
+
+    // Without admin auth the oracle cannot be changed
+    env.set_auths(&[]);
+    let res = client.try_set_oracle(&oracle);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token, &oracle, &registry.address);
+
+    // Nothing to accept yet
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(VaultError::NoPendingAdmin)));
+
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // The proposed admin must authorize the acceptance
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+
+    // Admin-only calls now require the new admin
+    client.set_fee_config(&100, &treasury);
+    assert_eq!(env.auths()[0].0, new_admin);
+
+    // The proposal is consumed
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(VaultError::NoPendingAdmin)));
+}
+
+#[test]
+fn test_set_token_blocked_while_bookings_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let new_token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+    new_token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_my_rate(&expert, &10_i128);
+
+    // An accepted booking still holds escrow in the old token
+    let booking_id = client.book_session(&user, &expert, &100);
+    client.accept_session(&expert, &booking_id);
+    let res = client.try_set_token(&new_token.address);
+    assert_eq!(res, Err(Ok(VaultError::BookingsStillOpen)));
+
+    // Once settled, the token can be switched
+    client.start_session(&booking_id);
+    client.finalize_session(&booking_id, &100);
+    let res = client.try_set_token(&new_token.address);
+    assert_eq!(res, Err(Ok(VaultError::BookingsStillOpen)));
+    settle_after_window(&env, &client, booking_id);
+    client.set_token(&new_token.address);
+
+    // New bookings escrow the new token
+    client.book_session(&user, &expert, &100);
+    assert_eq!(new_token.balance(&client.address), 1_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_upgrade_from_v1_fixture_and_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let new_token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    // Deploy the version 1 vault and book through its own entrypoints
+    let contract_id = env.register(v1::WASM, ());
+    let old = v1::Client::new(&env, &contract_id);
+    old.init(&admin, &token.address, &oracle);
+    old.set_my_rate(&expert, &10_i128);
+    let settled_id = old.book_session(&user, &expert, &100);
+    let open_id = old.book_session(&user, &expert, &100);
+    let stale_id = old.book_session(&user, &expert, &100);
+    old.finalize_session(&settled_id, &40);
+    assert_eq!(token.balance(&expert), 400);
+
+    // Version 1 has no upgrade entrypoint, so swap the code from inside the contract
+    let wasm_hash = env.deployer().upload_contract_wasm(VAULT_WASM);
+    env.as_contract(&contract_id, || {
+        env.deployer()
+            .update_current_contract_wasm(wasm_hash.clone())
+    });
+
+    let client = PaymentVaultContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_version(), 1);
+
+    // Bookings can be used before the migration reaches them
+    env.ledger().set_timestamp(RECLAIM_TIMEOUT + 1);
+    client.reclaim_stale_session(&user, &stale_id);
+    assert_eq!(token.balance(&user), 8_600);
+
+    assert_eq!(client.migrate(&0u64, &2u32), Some(2));
+    assert_eq!(client.migrate(&2u64, &1u32), None);
+    assert_eq!(client.get_version(), 2);
+
+    // Bookings written by version 1 read back in the current layout
+    let settled = client.get_booking(&settled_id).unwrap();
+    assert_eq!(settled.status, BookingStatus::Complete);
+    assert_eq!(settled.fee_bps, 0);
+    let open = client.get_booking(&open_id).unwrap();
+    assert_eq!(open.status, BookingStatus::Pending);
+    assert_eq!(open.total_deposit, 1_000);
+    let stale = client.get_booking(&stale_id).unwrap();
+    assert_eq!(stale.status, BookingStatus::Reclaimed);
+
+    // The open booking still holds escrow in the original token
+    let res = client.try_set_token(&new_token.address);
+    assert_eq!(res, Err(Ok(VaultError::BookingsStillOpen)));
+
+    // The current code runs the booking made before the upgrade to the end
+    let registry = create_registry(&env, &admin);
+    client.set_registry(&registry.address);
+    client.accept_session(&expert, &open_id);
+    client.start_session(&open_id);
+    client.finalize_session(&open_id, &50);
+    settle_after_window(&env, &client, open_id);
+    assert_eq!(token.balance(&expert), 900);
+    assert_eq!(token.balance(&user), 9_100);
+    client.set_token(&new_token.address);
+
+    // Upgrading the new code requires the admin
+    env.set_auths(&[]);
+    let res = client.try_upgrade(&wasm_hash);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_migrate_rewrites_v1_bookings() {
+    use crate::migration::BookingRecordV1;
+    use crate::storage::{self, DataKey};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let new_token = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+
+    // Simulate one pending and one completed booking left by a version 1 deployment
+    env.as_contract(&client.address, || {
+        for (id, status) in [
+            (1u64, BookingStatus::Pending),
+            (2u64, BookingStatus::Complete),
+        ] {
+            let old = BookingRecordV1 {
+                id,
+                user: user.clone(),
+                expert: expert.clone(),
+                rate_per_second: 10,
+                max_duration: 100,
+                total_deposit: 1_000,
+                status,
+                created_at: 7,
+            };
+            env.storage().persistent().set(&DataKey::Booking(id), &old);
+            storage::get_next_booking_id(&env);
+        }
+        env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(client.get_version(), 1);
+
+    // Pages can be retried; the version only moves once the last one is done
+    assert_eq!(client.migrate(&0u64, &1u32), Some(1));
+    assert_eq!(client.migrate(&0u64, &1u32), Some(1));
+    assert_eq!(client.get_version(), 1);
+    assert_eq!(client.migrate(&1u64, &1u32), None);
+    assert_eq!(client.get_version(), 2);
+
+    let booking = client.get_booking(&1).unwrap();
+    assert_eq!(booking.status, BookingStatus::Pending);
+    assert_eq!(booking.created_at, 7);
+    assert_eq!(booking.accepted_at, None);
+    assert_eq!(booking.withdrawn, 0);
+    assert_eq!(booking.fee_bps, 0);
+    assert_eq!(
+        client.get_booking(&2).unwrap().status,
+        BookingStatus::Complete
+    );
+
+    // The rebuilt open-bookings count still protects the pending escrow
+    let res = client.try_set_token(&new_token);
+    assert_eq!(res, Err(Ok(VaultError::BookingsStillOpen)));
+
+    let res = client.try_migrate(&0u64, &10u32);
+    assert_eq!(res, Err(Ok(VaultError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_pause_blocks_groups_but_not_reclaim() {
+    use crate::types::PauseGroup;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &expert);
+    client.set_my_rate(&expert, &10_i128);
+    client.set_guardian(&guardian);
+
+    let booking_id = client.book_session(&user, &expert, &100_u64);
+    let started_id = client.book_session(&user, &expert, &100_u64);
+    client.accept_session(&expert, &started_id);
+    client.start_session(&started_id);
+
+    client.pause(&guardian, &PauseGroup::Bookings);
+    assert!(client.is_paused(&PauseGroup::Bookings));
+    assert!(!client.is_paused(&PauseGroup::Finalization));
+
+    let res = client.try_book_session(&user, &expert, &100_u64);
+    assert_eq!(res, Err(Ok(VaultError::ContractPaused)));
+    let res = client.try_accept_session(&expert, &booking_id);
+    assert_eq!(res, Err(Ok(VaultError::ContractPaused)));
+    let res = client.try_start_session(&booking_id);
+    assert_eq!(res, Err(Ok(VaultError::ContractPaused)));
+
+    client.pause(&admin, &PauseGroup::Finalization);
+    let res = client.try_finalize_session(&started_id, &50_u64);
+    assert_eq!(res, Err(Ok(VaultError::ContractPaused)));
+
+    // Experts can still collect what they earned while everything is paused
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+    assert_eq!(client.withdraw_accrued(&started_id), 100);
+    assert_eq!(token.balance(&expert), 100);
+
+    // Users can still get their deposit back while everything is paused
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 90_000);
+    client.reclaim_stale_session(&user, &booking_id);
+    assert_eq!(token.balance(&user), 9_000);
+
+    client.unpause(&guardian, &PauseGroup::Finalization);
+    assert!(!client.is_paused(&PauseGroup::Finalization));
+    client.finalize_session(&started_id, &50_u64);
+    settle_after_window(&env, &client, started_id);
+    assert_eq!(token.balance(&expert), 500);
+}
+
+#[test]
+fn test_pause_requires_admin_or_guardian() {
+    use crate::types::PauseGroup;
+    use soroban_sdk::{testutils::Events, IntoVal, Symbol, TryIntoVal};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+
+    let res = client.try_pause(&stranger, &PauseGroup::Bookings);
+    assert_eq!(res, Err(Ok(VaultError::NotAuthorized)));
+
+    client.pause(&admin, &PauseGroup::Bookings);
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let topic: Symbol = event.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "paused"));
+    let by: Address = event.2.try_into_val(&env).unwrap();
+    assert_eq!(by, admin);
+
+    // Appointing a guardian later lets them lift the pause
+    let res = client.try_unpause(&stranger, &PauseGroup::Bookings);
+    assert_eq!(res, Err(Ok(VaultError::NotAuthorized)));
+    client.set_guardian(&stranger);
+    client.unpause(&stranger, &PauseGroup::Bookings);
+    assert_eq!(
+        env.events().all().last().unwrap().1,
+        (Symbol::new(&env, "unpaused"), PauseGroup::Bookings).into_val(&env)
+    );
+    assert!(!client.is_paused(&PauseGroup::Bookings));
+}
+
+#[test]
+fn test_migrate_expert_follows_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let old_expert = Address::generate(&env);
+    let new_expert = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&user, &10_000);
+
+    let client = create_client(&env);
+    let registry = create_registry(&env, &admin);
+    client.init(&admin, &token.address, &oracle, &registry.address);
+    verify_expert(&env, &registry, &admin, &old_expert);
+    client.set_my_rate(&old_expert, &10_i128);
+    let rejected_id = client.book_session(&user, &old_expert, &100_u64);
+    client.reject_session(&old_expert, &rejected_id);
+    let booking_id = client.book_session(&user, &old_expert, &100_u64);
+    client.accept_session(&old_expert, &booking_id);
+
+    // Nothing to follow until the registry has migrated the expert
+    let res = client.try_migrate_expert(&old_expert, &0u32, &10u32);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotMigrated)));
+
+    // One booking per page; the list only moves with the last page
+    registry.migrate_identity(&old_expert, &new_expert);
+    assert_eq!(client.migrate_expert(&old_expert, &0u32, &1u32), Some(1));
+    assert_eq!(client.get_expert_bookings(&old_expert).len(), 2);
+    assert_eq!(client.migrate_expert(&old_expert, &1u32, &1u32), None);
+
+    assert_eq!(client.get_expert_bookings(&old_expert).len(), 0);
+    assert_eq!(
+        client.get_expert_bookings(&new_expert),
+        vec![&env, rejected_id, booking_id]
+    );
+    assert_eq!(client.get_booking(&booking_id).unwrap().expert, new_expert);
+    // Finished bookings are left as they were paid
+    assert_eq!(client.get_booking(&rejected_id).unwrap().expert, old_expert);
+
+    // The session pays out to the new key, and the rate moved too
+    client.start_session(&booking_id);
+    client.finalize_session(&booking_id, &50_u64);
+    settle_after_window(&env, &client, booking_id);
+    assert_eq!(token.balance(&new_expert), 500);
+    assert_eq!(token.balance(&old_expert), 0);
+
+    let next = client.book_session(&user, &new_expert, &10_u64);
+    assert_eq!(client.get_booking(&next).unwrap().rate_per_second, 10);
+    let res = client.try_book_session(&user, &old_expert, &10_u64);
+    assert_eq!(res, Err(Ok(VaultError::ExpertNotVerified)));
+
+    // Running it again has nothing left to move
+    assert_eq!(client.migrate_expert(&old_expert, &0u32, &10u32), None);
+    assert_eq!(client.get_expert_bookings(&new_expert).len(), 3);
+}
@@ -0,0 +1,101 @@
+use crate::error::VaultError;
+use crate::types::{BookingRecord, BookingStatus};
+
+/// 24 hours in seconds
+/// How long a Pending booking waits for the expert before the user can reclaim it
+pub const RECLAIM_TIMEOUT: u64 = 86400;
+
+/// 48 hours in seconds
+/// How long an Accepted booking waits for the session to start before the user can reclaim it
+pub const NO_SHOW_TIMEOUT: u64 = 172800;
+
+/// 24 hours in seconds
+/// How long a Finalized session stays open to disputes before it can be settled
+pub const SETTLEMENT_WINDOW: u64 = 86400;
+
+// Booking state machine
+//
+//   Pending    -> Accepted | Rejected | Reclaimed
+//   Accepted   -> InProgress | Rejected | Reclaimed
+//   InProgress -> Finalized | Reclaimed | Disputed
+//   Finalized  -> Complete | Disputed
+//   Disputed   -> Resolved
+//
+// Complete, Rejected, Reclaimed and Resolved are terminal.
+// A Finalized booking still holds the escrow so the Oracle's duration can be contested.
+// A Disputed booking is frozen: it can only leave through the arbitrator.
+// Nothing can be disputed before the session starts; the expert can reject
+// and the user can reclaim instead.
+
+/// Returns true if a booking may move from `from` to `to`
+pub fn can_transition(from: BookingStatus, to: BookingStatus) -> bool {
+    use BookingStatus::*;
+
+    matches!(
+        (from, to),
+        (Pending, Accepted)
+            | (Pending, Rejected)
+            | (Pending, Reclaimed)
+            | (Accepted, InProgress)
+            | (Accepted, Rejected)
+            | (Accepted, Reclaimed)
+            | (InProgress, Finalized)
+            | (InProgress, Reclaimed)
+            | (InProgress, Disputed)
+            | (Finalized, Complete)
+            | (Finalized, Disputed)
+            | (Disputed, Resolved)
+    )
+}
+
+/// Returns true if no further moves are possible (escrow has been released)
+pub fn is_terminal(status: BookingStatus) -> bool {
+    matches!(
+        status,
+        BookingStatus::Complete
+            | BookingStatus::Rejected
+            | BookingStatus::Reclaimed
+            | BookingStatus::Resolved
+    )
+}
+
+/// Validate a status change against the transition table
+pub fn ensure_transition(from: BookingStatus, to: BookingStatus) -> Result<(), VaultError> {
+    if !can_transition(from, to) {
+        return Err(VaultError::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// Earliest timestamp after which the user may reclaim the booking
+/// - Pending: the expert never answered within RECLAIM_TIMEOUT
+/// - Accepted: the session did not start within NO_SHOW_TIMEOUT of acceptance
+/// - InProgress: the Oracle did not finalize within RECLAIM_TIMEOUT of the booked end
+pub fn reclaimable_after(booking: &BookingRecord) -> u64 {
+    match booking.status {
+        BookingStatus::Accepted => {
+            booking.accepted_at.unwrap_or(booking.created_at) + NO_SHOW_TIMEOUT
+        }
+        BookingStatus::InProgress => {
+            booking.started_at.unwrap_or(booking.created_at)
+                + booking.max_duration
+                + RECLAIM_TIMEOUT
+        }
+        _ => booking.created_at + RECLAIM_TIMEOUT,
+    }
+}
+
+/// Earliest timestamp after which a Finalized booking may be settled
+pub fn settleable_after(booking: &BookingRecord) -> u64 {
+    booking.finalized_at.unwrap_or(booking.created_at) + SETTLEMENT_WINDOW
+}
+
+/// A dispute can be opened until the booking becomes reclaimable,
+/// so the user can never race the other party between reclaiming and disputing.
+/// Once finalized, the window runs until the booking can be settled.
+pub fn dispute_window_open(booking: &BookingRecord, now: u64) -> bool {
+    match booking.status {
+        BookingStatus::Finalized => now <= settleable_after(booking),
+        _ => now <= reclaimable_after(booking),
+    }
+}
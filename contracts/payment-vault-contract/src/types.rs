@@ -9,18 +9,46 @@ pub enum BookingStatus {
     Complete = 1,
     Rejected = 2,
     Reclaimed = 3,
+    Accepted = 4,
+    InProgress = 5,
+    Disputed = 6,
+    Resolved = 7,
+    Finalized = 8,
+}
+
+/// Groups of entrypoints that can be paused together
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PauseGroup {
+    Bookings = 0,     // book_session, accept_session, start_session
+    Finalization = 1, // finalize_session, settle_session, resolve_dispute
 }
 
 /// Record of a consultation booking with deposit locked
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BookingRecord {
-    pub id: u64,                    // Storage key identifier
-    pub user: Address,              // User who created the booking
-    pub expert: Address,            // Expert providing consultation
-    pub rate_per_second: i128,      // Payment rate per second
-    pub max_duration: u64,          // Maximum booked duration in seconds
-    pub total_deposit: i128,        // Total deposit (rate_per_second * max_duration)
-    pub status: BookingStatus,      // Current booking status
-    pub created_at: u64,            // Ledger timestamp when booking was created
+    pub id: u64,                   // Storage key identifier
+    pub user: Address,             // User who created the booking
+    pub expert: Address,           // Expert providing consultation
+    pub rate_per_second: i128,     // Payment rate per second
+    pub max_duration: u64,         // Maximum booked duration in seconds
+    pub total_deposit: i128,       // Total deposit (rate_per_second * max_duration)
+    pub status: BookingStatus,     // Current booking status
+    pub created_at: u64,           // Ledger timestamp when booking was created
+    pub accepted_at: Option<u64>,  // Ledger timestamp when the expert accepted the booking
+    pub started_at: Option<u64>,   // Ledger timestamp when the session started streaming
+    pub withdrawn: i128,           // Gross amount already streamed to the expert (fees included)
+    pub fee_bps: u32,              // Platform fee in basis points, fixed at booking time
+    pub finalized_at: Option<u64>, // Ledger timestamp when the Oracle reported the duration
+    pub final_pay: i128,           // Gross expert pay reported by the Oracle, held until settlement
+}
+
+/// Platform fee configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32,              // Fee in basis points (1 bps = 0.01%)
+    pub treasury: Option<Address>, // Recipient of collected fees
 }